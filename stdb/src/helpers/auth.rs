@@ -1,8 +1,12 @@
 use spacetimedb::{Identity, ReducerContext, Table};
 
+use crate::tables::agent::Agent;
+use crate::tables::config::config;
 use crate::tables::identity_role::{IdentityRole, identity_roles};
 use crate::types::AgentRole;
 
+pub const GITHUB_INTEGRATION_IDENTITY_KEY: &str = "github_integration_identity";
+
 const ZOE_IDENTITIES: &[&str] = &[
     "c20042e1bccdf9eeddb52cf0d58d99e8c31eeba188fd98bc91c206f86b3b8955",
     "c200c7d4c23d986d28a5f033f523a9b79eb4b8472df63cff2998518d27bdd0ba",
@@ -37,6 +41,36 @@ pub fn require_role(ctx: &ReducerContext, role: AgentRole) -> Result<(), String>
     }
 }
 
+/// Suspended agents keep read access but cannot vote, claim, report,
+/// propose or post until an admin reinstates them.
+pub fn require_not_suspended(agent: &Agent) -> Result<(), String> {
+    if agent.suspended_at.is_none() {
+        return Ok(());
+    }
+
+    match &agent.suspension_reason {
+        Some(reason) => Err(format!("Agent is suspended: {}", reason)),
+        None => Err("Agent is suspended".to_string()),
+    }
+}
+
+pub fn is_github_integration(ctx: &ReducerContext, identity: &Identity) -> bool {
+    ctx.db
+        .config()
+        .key()
+        .find(GITHUB_INTEGRATION_IDENTITY_KEY.to_string())
+        .and_then(|c| Identity::from_hex(&c.value).ok())
+        .is_some_and(|integration| integration == *identity)
+}
+
+pub fn require_github_integration(ctx: &ReducerContext) -> Result<(), String> {
+    if is_github_integration(ctx, &ctx.sender()) {
+        Ok(())
+    } else {
+        Err("Requires the GitHub integration identity".to_string())
+    }
+}
+
 pub fn get_role(ctx: &ReducerContext, identity: &Identity) -> Option<AgentRole> {
     ctx.db
         .identity_roles()
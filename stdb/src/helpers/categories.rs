@@ -0,0 +1,28 @@
+use spacetimedb::ReducerContext;
+
+use crate::tables::idea_category::{IdeaCategory, idea_categories};
+
+pub const DEFAULT_IDEA_CATEGORY: &str = "general";
+
+/// Category names are lowercase with single dashes, so `Dev Tooling` and
+/// `dev-tooling` name the same category.
+pub fn normalize_category_name(name: &str) -> String {
+    name.split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|part| !part.is_empty())
+        .map(|part| part.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+pub fn require_idea_category(ctx: &ReducerContext, name: &str) -> Result<IdeaCategory, String> {
+    let normalized = normalize_category_name(name);
+    if normalized.is_empty() {
+        return Err("Category required".to_string());
+    }
+
+    ctx.db
+        .idea_categories()
+        .name()
+        .find(&normalized)
+        .ok_or_else(|| format!("Unknown idea category: {}", normalized))
+}
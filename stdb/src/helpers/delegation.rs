@@ -0,0 +1,51 @@
+use spacetimedb::ReducerContext;
+use std::collections::{HashMap, HashSet};
+
+use crate::tables::vote_delegation::{VoteDelegation, vote_delegations};
+use crate::types::VoteType;
+
+/// The delegation an agent has in force for a category: a category-specific
+/// delegation takes precedence over a global one.
+pub fn find_delegation(
+    ctx: &ReducerContext,
+    delegator_id: &str,
+    category: &str,
+) -> Option<VoteDelegation> {
+    let mut global = None;
+    for delegation in ctx
+        .db
+        .vote_delegations()
+        .by_delegator_id()
+        .filter(&delegator_id.to_string())
+    {
+        match delegation.category.as_deref() {
+            Some(c) if c == category => return Some(delegation),
+            None => global = Some(delegation),
+            _ => {}
+        }
+    }
+    global
+}
+
+/// Follows an agent's delegation chain until it reaches an agent who voted
+/// directly. Returns `None` when the chain ends without a direct vote or loops
+/// back on itself.
+pub fn resolve_delegated_vote(
+    ctx: &ReducerContext,
+    agent_id: &str,
+    category: &str,
+    direct_votes: &HashMap<String, VoteType>,
+) -> Option<VoteType> {
+    let mut visited = HashSet::new();
+    let mut current = agent_id.to_string();
+
+    while visited.insert(current.clone()) {
+        let delegate_id = find_delegation(ctx, &current, category)?.delegate_id;
+        if let Some(vote_type) = direct_votes.get(&delegate_id) {
+            return Some(vote_type.clone());
+        }
+        current = delegate_id;
+    }
+
+    None
+}
@@ -0,0 +1,122 @@
+use spacetimedb::{ReducerContext, Table};
+use std::collections::BTreeSet;
+
+use crate::tables::config::config;
+use crate::tables::discovered_task::{DiscoveredTask, discovered_tasks};
+use crate::tables::discovery_duplicate_candidate::{
+    DiscoveryDuplicateCandidate, discovery_duplicate_candidates,
+};
+use crate::tables::task::tasks;
+use crate::types::{DiscoveredTaskStatus, DuplicateTarget, DuplicateTargetKind, TaskStatus};
+
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "at", "by", "for", "from", "in", "is", "it", "of", "on", "or", "the", "to",
+    "when", "with",
+];
+
+pub fn get_discovery_duplicate_threshold(ctx: &ReducerContext) -> f64 {
+    ctx.db
+        .config()
+        .key()
+        .find("discovery_duplicate_threshold".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .filter(|t: &f64| *t > 0.0 && *t <= 1.0)
+        .unwrap_or(0.6)
+}
+
+/// Lowercased alphanumeric words of a title, without stop words, so
+/// `Fix: crash in the parser` and `parser crash fix` compare equal.
+pub fn title_tokens(title: &str) -> BTreeSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|word| word.to_lowercase())
+        .filter(|word| !word.is_empty() && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Jaccard similarity of two token sets, from `0.0` (disjoint) to `1.0`.
+pub fn token_similarity(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let shared = a.intersection(b).count();
+    let total = a.union(b).count();
+    shared as f64 / total as f64
+}
+
+/// Records pending discoveries and open tasks in the same project whose
+/// titles are similar to the new discovery's, most similar first.
+pub fn record_duplicate_candidates(
+    ctx: &ReducerContext,
+    discovery: &DiscoveredTask,
+) -> Vec<DiscoveryDuplicateCandidate> {
+    let threshold = get_discovery_duplicate_threshold(ctx);
+    let tokens = title_tokens(&discovery.title);
+
+    let pending = ctx
+        .db
+        .discovered_tasks()
+        .by_status()
+        .filter(&DiscoveredTaskStatus::PendingReview)
+        .filter(|d| d.id != discovery.id && d.project_id == discovery.project_id)
+        .map(|d| (DuplicateTargetKind::Discovery, d.id, d.title));
+    let open_tasks = ctx
+        .db
+        .tasks()
+        .by_project_id()
+        .filter(&discovery.project_id)
+        .filter(|t| !matches!(t.status, TaskStatus::Completed | TaskStatus::Archived))
+        .map(|t| (DuplicateTargetKind::Task, t.id, t.title));
+
+    let mut matches: Vec<(DuplicateTargetKind, u64, f64)> = pending
+        .chain(open_tasks)
+        .filter_map(|(kind, id, title)| {
+            let similarity = token_similarity(&tokens, &title_tokens(&title));
+            (similarity >= threshold).then_some((kind, id, similarity))
+        })
+        .collect();
+    matches.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+    matches
+        .into_iter()
+        .map(|(candidate_kind, candidate_id, similarity)| {
+            ctx.db
+                .discovery_duplicate_candidates()
+                .insert(DiscoveryDuplicateCandidate {
+                    id: 0,
+                    discovery_id: discovery.id,
+                    candidate_kind,
+                    candidate_id,
+                    similarity,
+                    created_at: ctx.timestamp,
+                })
+        })
+        .collect()
+}
+
+/// Removes candidate links from a discovery and links that point at it.
+pub fn clear_duplicate_candidates(ctx: &ReducerContext, target: &DuplicateTarget) {
+    let mut candidate_ids: Vec<u64> = ctx
+        .db
+        .discovery_duplicate_candidates()
+        .by_candidate()
+        .filter((&target.kind, &target.id))
+        .map(|c| c.id)
+        .collect();
+    if target.kind == DuplicateTargetKind::Discovery {
+        candidate_ids.extend(
+            ctx.db
+                .discovery_duplicate_candidates()
+                .by_discovery_id()
+                .filter(&target.id)
+                .map(|c| c.id),
+        );
+    }
+    for candidate_id in candidate_ids {
+        ctx.db
+            .discovery_duplicate_candidates()
+            .id()
+            .delete(candidate_id);
+    }
+}
@@ -0,0 +1,84 @@
+use spacetimedb::{ReducerContext, Table};
+use std::time::Duration;
+
+use crate::helpers::reputation::vote_weight;
+use crate::tables::agent::agents;
+use crate::tables::config::config;
+use crate::tables::idea_eligible_voter::{IdeaEligibleVoter, idea_eligible_voters};
+
+pub fn get_voter_min_age_days(ctx: &ReducerContext) -> u64 {
+    ctx.db
+        .config()
+        .key()
+        .find("voter_min_age_days".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(0)
+}
+
+pub fn proposer_can_vote(ctx: &ReducerContext) -> bool {
+    ctx.db
+        .config()
+        .key()
+        .find("proposer_can_vote".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(true)
+}
+
+/// Agents active within the activity window and registered for at least
+/// `voter_min_age_days`.
+pub fn eligible_agents(ctx: &ReducerContext, window_days: u64) -> Vec<String> {
+    let day_micros = 24 * 60 * 60 * 1_000_000;
+    let active_cutoff = ctx.timestamp - Duration::from_micros(window_days * day_micros);
+    let age_cutoff =
+        ctx.timestamp - Duration::from_micros(get_voter_min_age_days(ctx) * day_micros);
+
+    ctx.db
+        .agents()
+        .iter()
+        .filter(|a| a.last_active_at > active_cutoff && a.created_at <= age_cutoff)
+        .map(|a| a.id)
+        .collect()
+}
+
+/// Agents allowed to vote on a new idea: the eligible agents, without the
+/// proposer unless `proposer_can_vote` is set.
+pub fn eligible_voters(ctx: &ReducerContext, window_days: u64, proposer_id: &str) -> Vec<String> {
+    let include_proposer = proposer_can_vote(ctx);
+
+    eligible_agents(ctx, window_days)
+        .into_iter()
+        .filter(|id| include_proposer || id != proposer_id)
+        .collect()
+}
+
+/// Total vote weight of a voter snapshot; its headcount when `weighted` is off.
+pub fn snapshot_weight(ctx: &ReducerContext, agent_ids: &[String], weighted: bool) -> u32 {
+    if !weighted {
+        return agent_ids.len() as u32;
+    }
+
+    agent_ids
+        .iter()
+        .filter_map(|agent_id| ctx.db.agents().id().find(agent_id))
+        .map(|agent| vote_weight(ctx, &agent, true) as u32)
+        .sum()
+}
+
+pub fn record_eligible_voters(ctx: &ReducerContext, idea_id: u64, agent_ids: Vec<String>) {
+    for agent_id in agent_ids {
+        ctx.db.idea_eligible_voters().insert(IdeaEligibleVoter {
+            id: 0,
+            idea_id,
+            agent_id,
+            created_at: ctx.timestamp,
+        });
+    }
+}
+
+pub fn is_eligible_voter(ctx: &ReducerContext, idea_id: u64, agent_id: &str) -> bool {
+    ctx.db
+        .idea_eligible_voters()
+        .by_idea_agent()
+        .filter(&idea_id)
+        .any(|v| v.agent_id == agent_id)
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use spacetimedb::{ReducerContext, Table};
+
+use crate::tables::project::Project;
+use crate::tables::task::{Task, tasks};
+use crate::tables::task_github_link::{TaskGithubLink, task_github_links};
+use crate::types::GithubRefKind;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GithubRepo {
+    pub owner: String,
+    pub repo: String,
+}
+
+impl fmt::Display for GithubRepo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.owner, self.repo)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GithubRef {
+    pub repo: GithubRepo,
+    pub kind: GithubRefKind,
+    pub number: u64,
+}
+
+impl GithubRef {
+    pub fn url(&self) -> String {
+        let section = match self.kind {
+            GithubRefKind::Issue => "issues",
+            GithubRefKind::PullRequest => "pull",
+        };
+        format!(
+            "https://github.com/{}/{}/{}/{}",
+            self.repo.owner, self.repo.repo, section, self.number
+        )
+    }
+}
+
+impl fmt::Display for GithubRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}", self.repo, self.number)
+    }
+}
+
+fn is_valid_owner(owner: &str) -> bool {
+    !owner.is_empty()
+        && owner.len() <= 39
+        && !owner.starts_with('-')
+        && owner.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+fn is_valid_repo(repo: &str) -> bool {
+    !repo.is_empty()
+        && repo.len() <= 100
+        && repo != "."
+        && repo != ".."
+        && repo
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Strips scheme, host, query and fragment, returning the lowercased path
+/// segments. Bare paths are only accepted when `allow_bare` is set.
+fn github_path_segments(input: &str, allow_bare: bool) -> Option<Vec<String>> {
+    let lowered = input.trim().to_ascii_lowercase();
+    let without_scheme = lowered
+        .strip_prefix("https://")
+        .or_else(|| lowered.strip_prefix("http://"))
+        .unwrap_or(lowered.as_str());
+    let without_www = without_scheme
+        .strip_prefix("www.")
+        .unwrap_or(without_scheme);
+
+    let path = match without_www.strip_prefix("github.com/") {
+        Some(path) => path,
+        None if allow_bare && without_scheme.len() == lowered.len() => without_www,
+        None => return None,
+    };
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    Some(
+        path.trim_end_matches('/')
+            .split('/')
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+fn repo_from_segments(owner: &str, repo: &str) -> Option<GithubRepo> {
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    if is_valid_owner(owner) && is_valid_repo(repo) {
+        Some(GithubRepo {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+        })
+    } else {
+        None
+    }
+}
+
+/// Accepts `owner/repo`, `github.com/owner/repo` or a full repository URL.
+pub fn parse_github_repo(input: &str) -> Result<GithubRepo, String> {
+    github_path_segments(input, true)
+        .filter(|segments| segments.len() == 2)
+        .and_then(|segments| repo_from_segments(&segments[0], &segments[1]))
+        .ok_or_else(|| format!("Invalid GitHub repository: {}", input.trim()))
+}
+
+/// Parses an issue (`/issues/N`) or pull request (`/pull/N`) URL. The
+/// shorthand `owner/repo#N` is accepted for either kind.
+pub fn parse_github_url(input: &str, kind: GithubRefKind) -> Result<GithubRef, String> {
+    let invalid = || format!("Invalid GitHub {} URL: {}", kind.as_str(), input.trim());
+
+    if let Some((repo, number)) = input.trim().split_once('#') {
+        if !repo.contains("github.com") {
+            let repo = parse_github_repo(repo).map_err(|_| invalid())?;
+            let number = number
+                .parse::<u64>()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or_else(invalid)?;
+            return Ok(GithubRef { repo, kind, number });
+        }
+    }
+
+    let segments = github_path_segments(input, false).ok_or_else(invalid)?;
+    if segments.len() < 4 {
+        return Err(invalid());
+    }
+
+    let section_matches = match kind {
+        GithubRefKind::Issue => segments[2] == "issues",
+        GithubRefKind::PullRequest => segments[2] == "pull" || segments[2] == "pulls",
+    };
+    if !section_matches {
+        return Err(invalid());
+    }
+
+    let repo = repo_from_segments(&segments[0], &segments[1]).ok_or_else(invalid)?;
+    let number = segments[3]
+        .parse::<u64>()
+        .ok()
+        .filter(|n| *n > 0)
+        .ok_or_else(invalid)?;
+
+    Ok(GithubRef { repo, kind, number })
+}
+
+/// Parses a URL and rejects it unless it points at the project's repository.
+pub fn parse_project_github_url(
+    project: &Project,
+    input: &str,
+    kind: GithubRefKind,
+) -> Result<GithubRef, String> {
+    let project_repo = parse_github_repo(&project.github_repo)
+        .map_err(|_| format!("Project {} has an invalid github_repo", project.id))?;
+    let github_ref = parse_github_url(input, kind)?;
+
+    if github_ref.repo != project_repo {
+        return Err(format!(
+            "{} does not belong to project repository {}",
+            github_ref, project_repo
+        ));
+    }
+
+    Ok(github_ref)
+}
+
+/// Replaces the task's link of the same kind with `github_ref`.
+pub fn link_task_github_ref(ctx: &ReducerContext, task: &Task, github_ref: &GithubRef) {
+    let stale: Vec<u64> = ctx
+        .db
+        .task_github_links()
+        .by_task_id()
+        .filter(&task.id)
+        .filter(|link| link.kind == github_ref.kind)
+        .map(|link| link.id)
+        .collect();
+
+    for link_id in stale {
+        ctx.db.task_github_links().id().delete(link_id);
+    }
+
+    ctx.db.task_github_links().insert(TaskGithubLink {
+        id: 0,
+        task_id: task.id,
+        project_id: task.project_id,
+        kind: github_ref.kind.clone(),
+        owner: github_ref.repo.owner.clone(),
+        repo: github_ref.repo.repo.clone(),
+        number: github_ref.number,
+        created_at: ctx.timestamp,
+    });
+}
+
+pub fn find_tasks_by_github_ref(ctx: &ReducerContext, github_ref: &GithubRef) -> Vec<Task> {
+    ctx.db
+        .task_github_links()
+        .by_number()
+        .filter(&github_ref.number)
+        .filter(|link| {
+            link.kind == github_ref.kind
+                && link.owner == github_ref.repo.owner
+                && link.repo == github_ref.repo.repo
+        })
+        .filter_map(|link| ctx.db.tasks().id().find(link.task_id))
+        .collect()
+}
@@ -0,0 +1,206 @@
+use spacetimedb::{ReducerContext, ScheduleAt, Table};
+use std::time::Duration;
+
+use crate::helpers::categories::require_idea_category;
+use crate::helpers::severity::parse_severity_priorities;
+use crate::helpers::thresholds::params_after_changes;
+use crate::tables::config::{Config, config};
+use crate::tables::governance_execution::{GovernanceExecution, governance_executions};
+use crate::types::{AgentRole, ConfigChange};
+
+pub const GOVERNANCE_IDEA_CATEGORY: &str = "governance";
+
+/// How a governable config value is checked before a proposal is accepted and
+/// again right before it is applied.
+enum ValueKind {
+    Count { min: u64 },
+    Flag,
+    Role,
+    Ratio,
+    ReputationTiers,
+    AmendmentPolicy,
+    SeverityPriorities,
+}
+
+/// Keys agents may change through governance proposals. Keys that grant
+/// access, such as `github_integration_identity`, and
+/// `governance_veto_window_hours`, which guards Zoe's emergency veto, stay
+/// with admins.
+const GOVERNABLE_KEYS: &[(&str, ValueKind)] = &[
+    ("activity_window_days", ValueKind::Count { min: 1 }),
+    ("amendment_vote_policy", ValueKind::AmendmentPolicy),
+    ("discovery_duplicate_threshold", ValueKind::Ratio),
+    (
+        "discovery_severity_priorities",
+        ValueKind::SeverityPriorities,
+    ),
+    ("idea_sponsors_required", ValueKind::Count { min: 0 }),
+    ("proposer_can_vote", ValueKind::Flag),
+    ("soft_delete_retention_days", ValueKind::Count { min: 1 }),
+    ("veto_min_reputation", ValueKind::Count { min: 0 }),
+    ("veto_min_role", ValueKind::Role),
+    ("vote_weight_admin", ValueKind::Count { min: 1 }),
+    ("vote_weight_reputation_tiers", ValueKind::ReputationTiers),
+    ("vote_weight_zeno", ValueKind::Count { min: 1 }),
+    ("vote_weight_zoe", ValueKind::Count { min: 1 }),
+    ("voter_min_age_days", ValueKind::Count { min: 0 }),
+    ("voting_early_rejection", ValueKind::Flag),
+    ("voting_majority_rejection", ValueKind::Flag),
+    ("voting_window_days", ValueKind::Count { min: 1 }),
+    ("weighted_voting", ValueKind::Flag),
+];
+
+/// Threshold keys may also carry a `:<category>` suffix.
+const GOVERNABLE_THRESHOLD_KEYS: &[(&str, ValueKind)] = &[
+    ("governance_quorum_ratio", ValueKind::Ratio),
+    ("governance_approval_ratio", ValueKind::Ratio),
+    ("governance_veto_ratio", ValueKind::Ratio),
+    ("governance_quorum_floor", ValueKind::Count { min: 1 }),
+    ("governance_approval_floor", ValueKind::Count { min: 1 }),
+    ("governance_veto_floor", ValueKind::Count { min: 1 }),
+];
+
+fn is_threshold_key(key: &str) -> bool {
+    GOVERNABLE_THRESHOLD_KEYS.iter().any(|(k, _)| *k == key)
+}
+
+fn check_value(kind: &ValueKind, key: &str, value: &str) -> Result<(), String> {
+    let valid = match kind {
+        ValueKind::Count { min } => value.parse::<u16>().is_ok_and(|v| v as u64 >= *min),
+        ValueKind::Flag => value.parse::<bool>().is_ok(),
+        ValueKind::Role => AgentRole::parse(value).is_some(),
+        ValueKind::Ratio => value
+            .parse::<f64>()
+            .is_ok_and(|ratio| ratio > 0.0 && ratio <= 1.0),
+        ValueKind::ReputationTiers => value.split(',').all(|tier| {
+            tier.split_once(':').is_some_and(|(min_reputation, bonus)| {
+                min_reputation.trim().parse::<u32>().is_ok() && bonus.trim().parse::<u16>().is_ok()
+            })
+        }),
+        ValueKind::AmendmentPolicy => value == "reset" || value == "flag",
+        ValueKind::SeverityPriorities => parse_severity_priorities(value).is_some(),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid value '{}' for {}", value, key))
+    }
+}
+
+/// Checks that a proposed change targets a governable key and carries a value
+/// that key accepts.
+fn validate_config_change(ctx: &ReducerContext, change: &ConfigChange) -> Result<(), String> {
+    let value = change.value.trim();
+
+    if let Some((_, kind)) = GOVERNABLE_KEYS.iter().find(|(key, _)| *key == change.key) {
+        return check_value(kind, &change.key, value);
+    }
+
+    let (base, category) = match change.key.split_once(':') {
+        Some((base, category)) => (base, Some(category)),
+        None => (change.key.as_str(), None),
+    };
+    let Some((_, kind)) = GOVERNABLE_THRESHOLD_KEYS
+        .iter()
+        .find(|(key, _)| *key == base)
+    else {
+        return Err(format!("Config key '{}' is not governable", change.key));
+    };
+
+    if let Some(category) = category {
+        let found = require_idea_category(ctx, category)?;
+        if found.name != category {
+            return Err(format!("Use the normalized category name '{}'", found.name));
+        }
+    }
+
+    check_value(kind, &change.key, value)
+}
+
+/// Validates every change, then the complete threshold parameter set of each
+/// scope the changes touch.
+pub fn validate_config_changes(
+    ctx: &ReducerContext,
+    changes: &[ConfigChange],
+) -> Result<(), String> {
+    if changes.is_empty() {
+        return Err("A governance proposal needs at least one config change".to_string());
+    }
+
+    for (i, change) in changes.iter().enumerate() {
+        if changes[..i].iter().any(|c| c.key == change.key) {
+            return Err(format!(
+                "Config key '{}' appears more than once",
+                change.key
+            ));
+        }
+        validate_config_change(ctx, change)?;
+    }
+
+    let mut scopes: Vec<Option<&str>> = Vec::new();
+    for change in changes {
+        let scope = match change.key.split_once(':') {
+            Some((base, category)) if is_threshold_key(base) => Some(category),
+            None if is_threshold_key(&change.key) => None,
+            _ => continue,
+        };
+        if !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+    }
+    for scope in scopes {
+        params_after_changes(ctx, scope, changes)?;
+    }
+
+    Ok(())
+}
+
+pub fn upsert_config(ctx: &ReducerContext, key: String, value: String) {
+    let entry = Config { key, value };
+    if ctx.db.config().key().find(entry.key.clone()).is_some() {
+        ctx.db.config().key().update(entry);
+    } else {
+        ctx.db.config().insert(entry);
+    }
+}
+
+pub fn get_governance_veto_window_hours(ctx: &ReducerContext) -> u64 {
+    ctx.db
+        .config()
+        .key()
+        .find("governance_veto_window_hours".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(24)
+}
+
+/// Queues an approved governance idea for execution once Zoe's emergency veto
+/// window has passed.
+pub fn schedule_governance_execution(ctx: &ReducerContext, idea_id: u64) {
+    let window_micros = get_governance_veto_window_hours(ctx) * 60 * 60 * 1_000_000;
+    let executes_at = ctx.timestamp + Duration::from_micros(window_micros);
+    ctx.db.governance_executions().insert(GovernanceExecution {
+        scheduled_id: 0,
+        idea_id,
+        scheduled_at: ScheduleAt::Time(executes_at),
+    });
+}
+
+/// Removes a pending execution; returns whether one was pending.
+pub fn clear_governance_execution(ctx: &ReducerContext, idea_id: u64) -> bool {
+    let scheduled_ids: Vec<u64> = ctx
+        .db
+        .governance_executions()
+        .by_idea_id()
+        .filter(&idea_id)
+        .map(|e| e.scheduled_id)
+        .collect();
+    let pending = !scheduled_ids.is_empty();
+    for scheduled_id in scheduled_ids {
+        ctx.db
+            .governance_executions()
+            .scheduled_id()
+            .delete(scheduled_id);
+    }
+    pending
+}
@@ -0,0 +1,11 @@
+/// Lowercases, trims and de-duplicates labels, dropping empty ones.
+pub fn normalize_labels(labels: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for label in labels {
+        let label = label.trim().to_lowercase();
+        if !label.is_empty() && !normalized.contains(&label) {
+            normalized.push(label);
+        }
+    }
+    normalized
+}
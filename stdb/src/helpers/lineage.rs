@@ -0,0 +1,56 @@
+use spacetimedb::{ReducerContext, Table};
+
+use crate::tables::task_dependency::{TaskDependency, task_dependencies};
+use crate::tables::task_lineage::{TaskLineage, task_lineage};
+use crate::types::DependencyType;
+
+/// Links a task created from a discovery to the task it was discovered from.
+/// The `discovered-from` edge records the direct parent; `task_lineage` gets
+/// one row per ancestor so a task's whole tree can be read by index.
+pub fn record_discovered_from(
+    ctx: &ReducerContext,
+    task_id: u64,
+    origin_id: u64,
+    discovery_id: u64,
+) {
+    ctx.db.task_dependencies().insert(TaskDependency {
+        id: 0,
+        task_id,
+        depends_on_id: origin_id,
+        dependency_type: DependencyType::DiscoveredFrom,
+        created_at: ctx.timestamp,
+    });
+
+    let inherited: Vec<(u64, u32)> = ctx
+        .db
+        .task_lineage()
+        .by_task_id()
+        .filter(&origin_id)
+        .map(|l| (l.ancestor_id, l.depth + 1))
+        .collect();
+
+    for (ancestor_id, depth) in std::iter::once((origin_id, 1)).chain(inherited) {
+        ctx.db.task_lineage().insert(TaskLineage {
+            id: 0,
+            task_id,
+            ancestor_id,
+            depth,
+            discovery_id,
+            created_at: ctx.timestamp,
+        });
+    }
+}
+
+pub fn clear_task_lineage(ctx: &ReducerContext, task_id: u64) {
+    let lineage_ids: Vec<u64> = ctx
+        .db
+        .task_lineage()
+        .by_task_id()
+        .filter(&task_id)
+        .chain(ctx.db.task_lineage().by_ancestor_id().filter(&task_id))
+        .map(|l| l.id)
+        .collect();
+    for lineage_id in lineage_ids {
+        ctx.db.task_lineage().id().delete(lineage_id);
+    }
+}
@@ -1,3 +1,15 @@
 pub mod activity;
 pub mod auth;
+pub mod categories;
+pub mod delegation;
+pub mod duplicates;
+pub mod eligibility;
+pub mod github;
+pub mod governance;
+pub mod labels;
+pub mod lineage;
+pub mod reputation;
+pub mod retention;
+pub mod severity;
 pub mod thresholds;
+pub mod voting;
@@ -0,0 +1,137 @@
+use spacetimedb::ReducerContext;
+
+use crate::helpers::auth::{get_role, has_role};
+use crate::tables::agent::Agent;
+use crate::tables::config::config;
+use crate::tables::task::tasks;
+use crate::types::{AgentRole, TaskStatus};
+
+/// An agent's reputation is the number of tasks it has seen through to
+/// `Completed`.
+pub fn agent_reputation(ctx: &ReducerContext, agent_id: &str) -> u32 {
+    ctx.db
+        .tasks()
+        .by_status()
+        .filter(&TaskStatus::Completed)
+        .filter(|t| t.assigned_to.as_deref() == Some(agent_id))
+        .count() as u32
+}
+
+/// Registered agents always hold the base `Zeno` role; higher roles come from
+/// `identity_roles`.
+pub fn meets_role(ctx: &ReducerContext, agent: &Agent, min_role: AgentRole) -> bool {
+    match min_role {
+        AgentRole::Zeno => true,
+        role => has_role(ctx, &agent.identity, role),
+    }
+}
+
+pub fn get_veto_min_role(ctx: &ReducerContext) -> AgentRole {
+    ctx.db
+        .config()
+        .key()
+        .find("veto_min_role".to_string())
+        .and_then(|c| AgentRole::parse(&c.value))
+        .unwrap_or(AgentRole::Zeno)
+}
+
+pub fn get_veto_min_reputation(ctx: &ReducerContext) -> u32 {
+    ctx.db
+        .config()
+        .key()
+        .find("veto_min_reputation".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(3)
+}
+
+/// Veto rights need the configured minimum role and, below admin, the
+/// configured minimum reputation.
+pub fn require_veto_rights(ctx: &ReducerContext, agent: &Agent) -> Result<(), String> {
+    let min_role = get_veto_min_role(ctx);
+    if !meets_role(ctx, agent, min_role) {
+        return Err(format!("Veto requires the {} role", min_role.as_str()));
+    }
+
+    if has_role(ctx, &agent.identity, AgentRole::Admin) {
+        return Ok(());
+    }
+
+    let min_reputation = get_veto_min_reputation(ctx);
+    let reputation = agent_reputation(ctx, &agent.id);
+    if reputation < min_reputation {
+        return Err(format!(
+            "Veto requires {} completed tasks (has {})",
+            min_reputation, reputation
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn weighted_voting_enabled(ctx: &ReducerContext) -> bool {
+    ctx.db
+        .config()
+        .key()
+        .find("weighted_voting".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(false)
+}
+
+fn get_role_weight(ctx: &ReducerContext, role: AgentRole) -> u16 {
+    let default = match role {
+        AgentRole::Zoe => 3,
+        AgentRole::Admin => 2,
+        AgentRole::Zeno => 1,
+    };
+    ctx.db
+        .config()
+        .key()
+        .find(format!("vote_weight_{}", role.as_str()))
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Parses `vote_weight_reputation_tiers`, a comma-separated list of
+/// `min_reputation:bonus` pairs such as `5:1,20:2`. Malformed pairs are
+/// skipped.
+fn get_reputation_tiers(ctx: &ReducerContext) -> Vec<(u32, u16)> {
+    let Some(entry) = ctx
+        .db
+        .config()
+        .key()
+        .find("vote_weight_reputation_tiers".to_string())
+    else {
+        return Vec::new();
+    };
+
+    entry
+        .value
+        .split(',')
+        .filter_map(|tier| {
+            let (min_reputation, bonus) = tier.split_once(':')?;
+            Some((
+                min_reputation.trim().parse().ok()?,
+                bonus.trim().parse().ok()?,
+            ))
+        })
+        .collect()
+}
+
+/// Weight of an agent's vote: one when the idea is unweighted, otherwise the
+/// role weight plus the bonus of the highest reputation tier reached.
+pub fn vote_weight(ctx: &ReducerContext, agent: &Agent, weighted: bool) -> u16 {
+    if !weighted {
+        return 1;
+    }
+
+    let role = get_role(ctx, &agent.identity).unwrap_or(AgentRole::Zeno);
+    let reputation = agent_reputation(ctx, &agent.id);
+    let bonus = get_reputation_tiers(ctx)
+        .into_iter()
+        .filter(|(min_reputation, _)| reputation >= *min_reputation)
+        .map(|(_, bonus)| bonus)
+        .max()
+        .unwrap_or(0);
+
+    get_role_weight(ctx, role).saturating_add(bonus).max(1)
+}
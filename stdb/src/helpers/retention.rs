@@ -0,0 +1,34 @@
+use spacetimedb::{ReducerContext, Timestamp};
+use std::time::Duration;
+
+use crate::tables::config::config;
+
+pub fn get_soft_delete_retention_days(ctx: &ReducerContext) -> u64 {
+    ctx.db
+        .config()
+        .key()
+        .find("soft_delete_retention_days".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(30)
+}
+
+pub fn get_notification_retention_days(ctx: &ReducerContext) -> u64 {
+    ctx.db
+        .config()
+        .key()
+        .find("notification_retention_days".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(30)
+}
+
+pub fn purge_after(ctx: &ReducerContext) -> Timestamp {
+    let retention_micros = get_soft_delete_retention_days(ctx) * 24 * 60 * 60 * 1_000_000;
+    ctx.timestamp + Duration::from_micros(retention_micros)
+}
+
+pub fn require_restorable(ctx: &ReducerContext, purge_after: Timestamp) -> Result<(), String> {
+    if purge_after <= ctx.timestamp {
+        return Err("Restore window has expired".to_string());
+    }
+    Ok(())
+}
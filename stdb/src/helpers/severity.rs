@@ -0,0 +1,64 @@
+use spacetimedb::ReducerContext;
+
+use crate::tables::config::config;
+use crate::types::{Severity, TaskType};
+
+/// Parses a discovery's task type, ignoring case and surrounding whitespace.
+pub fn parse_task_type(value: &str) -> Result<TaskType, String> {
+    TaskType::parse(&value.trim().to_lowercase()).ok_or_else(|| {
+        format!(
+            "Invalid task type '{}', expected one of: {}",
+            value,
+            TaskType::ALL.map(|t| t.as_str()).join(", ")
+        )
+    })
+}
+
+/// Parses a discovery's severity, ignoring case and surrounding whitespace.
+pub fn parse_severity(value: &str) -> Result<Severity, String> {
+    Severity::parse(&value.trim().to_lowercase()).ok_or_else(|| {
+        format!(
+            "Invalid severity '{}', expected one of: {}",
+            value,
+            Severity::ALL.map(|s| s.as_str()).join(", ")
+        )
+    })
+}
+
+fn default_priority(severity: &Severity) -> u8 {
+    match severity {
+        Severity::Low => 3,
+        Severity::Medium => 5,
+        Severity::High => 8,
+        Severity::Critical => 10,
+    }
+}
+
+/// Parses `discovery_severity_priorities`, a comma-separated list of
+/// `severity:priority` pairs such as `low:3,critical:10`. Returns `None` when
+/// any pair is malformed.
+pub fn parse_severity_priorities(value: &str) -> Option<Vec<(Severity, u8)>> {
+    value
+        .split(',')
+        .map(|pair| {
+            let (severity, priority) = pair.split_once(':')?;
+            Some((
+                Severity::parse(severity.trim())?,
+                priority.trim().parse().ok()?,
+            ))
+        })
+        .collect()
+}
+
+/// Task priority for an approved discovery. Severities missing from the
+/// config, or a malformed config, fall back to the built-in mapping.
+pub fn severity_priority(ctx: &ReducerContext, severity: &Severity) -> u8 {
+    ctx.db
+        .config()
+        .key()
+        .find("discovery_severity_priorities".to_string())
+        .and_then(|c| parse_severity_priorities(&c.value))
+        .and_then(|pairs| pairs.into_iter().find(|(s, _)| s == severity))
+        .map(|(_, priority)| priority)
+        .unwrap_or_else(|| default_priority(severity))
+}
@@ -1,6 +1,207 @@
-pub fn calculate_thresholds(active_count: u32) -> (u16, u16, u16) {
-    let quorum = ((active_count as f64 * 0.33).ceil() as u16).max(5);
-    let approval = ((quorum as f64 * 0.50).ceil() as u16).max(5);
-    let veto = ((quorum as f64 * 0.33).ceil() as u16).max(3);
-    (quorum, approval, veto)
+use spacetimedb::ReducerContext;
+
+use crate::tables::config::config;
+use crate::tables::idea_category::idea_categories;
+use crate::types::ConfigChange;
+
+pub const DEFAULT_GOVERNANCE_PROFILE: &str = "default";
+
+const QUORUM_RATIO_KEY: &str = "governance_quorum_ratio";
+const APPROVAL_RATIO_KEY: &str = "governance_approval_ratio";
+const VETO_RATIO_KEY: &str = "governance_veto_ratio";
+const QUORUM_FLOOR_KEY: &str = "governance_quorum_floor";
+const APPROVAL_FLOOR_KEY: &str = "governance_approval_floor";
+const VETO_FLOOR_KEY: &str = "governance_veto_floor";
+
+/// Ratios and floors used to derive an idea's voting thresholds. Quorum is a
+/// share of active agents; approval and veto are shares of the quorum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GovernanceParams {
+    pub quorum_ratio: f64,
+    pub approval_ratio: f64,
+    pub veto_ratio: f64,
+    pub quorum_floor: u16,
+    pub approval_floor: u16,
+    pub veto_floor: u16,
+}
+
+impl Default for GovernanceParams {
+    fn default() -> Self {
+        Self {
+            quorum_ratio: 0.33,
+            approval_ratio: 0.50,
+            veto_ratio: 0.33,
+            quorum_floor: 5,
+            approval_floor: 5,
+            veto_floor: 3,
+        }
+    }
+}
+
+impl GovernanceParams {
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, ratio) in [
+            ("quorum_ratio", self.quorum_ratio),
+            ("approval_ratio", self.approval_ratio),
+            ("veto_ratio", self.veto_ratio),
+        ] {
+            if !(ratio > 0.0 && ratio <= 1.0) {
+                return Err(format!("{} must be in (0, 1]", name));
+            }
+        }
+
+        if self.quorum_floor == 0 || self.approval_floor == 0 || self.veto_floor == 0 {
+            return Err("Floors must be at least 1".to_string());
+        }
+
+        if self.approval_floor > self.quorum_floor || self.veto_floor > self.quorum_floor {
+            return Err("Approval and veto floors cannot exceed the quorum floor".to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn entries(&self) -> [(&'static str, String); 6] {
+        [
+            (QUORUM_RATIO_KEY, self.quorum_ratio.to_string()),
+            (APPROVAL_RATIO_KEY, self.approval_ratio.to_string()),
+            (VETO_RATIO_KEY, self.veto_ratio.to_string()),
+            (QUORUM_FLOOR_KEY, self.quorum_floor.to_string()),
+            (APPROVAL_FLOOR_KEY, self.approval_floor.to_string()),
+            (VETO_FLOOR_KEY, self.veto_floor.to_string()),
+        ]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Thresholds {
+    pub quorum: u16,
+    pub approval: u16,
+    pub veto: u16,
+    pub profile: String,
+}
+
+/// Config key for a governance parameter, scoped to a category when one is
+/// given (e.g. `governance_quorum_ratio:research`).
+pub fn governance_key(base: &str, category: Option<&str>) -> String {
+    match category {
+        Some(category) => format!("{}:{}", base, category),
+        None => base.to_string(),
+    }
+}
+
+pub fn governance_profile(category: Option<&str>) -> String {
+    match category {
+        Some(category) => format!("category:{}", category),
+        None => DEFAULT_GOVERNANCE_PROFILE.to_string(),
+    }
+}
+
+fn read_param<T: std::str::FromStr>(
+    ctx: &ReducerContext,
+    base: &str,
+    category: Option<&str>,
+) -> Option<T> {
+    ctx.db
+        .config()
+        .key()
+        .find(governance_key(base, category))
+        .and_then(|c| c.value.parse().ok())
+}
+
+fn read_params(ctx: &ReducerContext, category: Option<&str>) -> Option<GovernanceParams> {
+    Some(GovernanceParams {
+        quorum_ratio: read_param(ctx, QUORUM_RATIO_KEY, category)?,
+        approval_ratio: read_param(ctx, APPROVAL_RATIO_KEY, category)?,
+        veto_ratio: read_param(ctx, VETO_RATIO_KEY, category)?,
+        quorum_floor: read_param(ctx, QUORUM_FLOOR_KEY, category)?,
+        approval_floor: read_param(ctx, APPROVAL_FLOOR_KEY, category)?,
+        veto_floor: read_param(ctx, VETO_FLOOR_KEY, category)?,
+    })
+    .filter(|params| params.validate().is_ok())
+}
+
+/// The parameter set a scope (a category, or the global keys) ends up with
+/// once `changes` are written over the current config. Fails when any of the
+/// six keys is missing or unparsable, or the set does not validate, since
+/// `read_params` would then silently fall back.
+pub fn params_after_changes(
+    ctx: &ReducerContext,
+    category: Option<&str>,
+    changes: &[ConfigChange],
+) -> Result<GovernanceParams, String> {
+    fn resolve<T: std::str::FromStr>(
+        ctx: &ReducerContext,
+        base: &str,
+        category: Option<&str>,
+        changes: &[ConfigChange],
+    ) -> Result<T, String> {
+        let key = governance_key(base, category);
+        let value = match changes.iter().find(|c| c.key == key) {
+            Some(change) => Some(change.value.clone()),
+            None => ctx.db.config().key().find(key.clone()).map(|c| c.value),
+        };
+        value
+            .ok_or_else(|| format!("{} must be set as well", key))?
+            .parse()
+            .map_err(|_| format!("Invalid value for {}", key))
+    }
+
+    let params = GovernanceParams {
+        quorum_ratio: resolve(ctx, QUORUM_RATIO_KEY, category, changes)?,
+        approval_ratio: resolve(ctx, APPROVAL_RATIO_KEY, category, changes)?,
+        veto_ratio: resolve(ctx, VETO_RATIO_KEY, category, changes)?,
+        quorum_floor: resolve(ctx, QUORUM_FLOOR_KEY, category, changes)?,
+        approval_floor: resolve(ctx, APPROVAL_FLOOR_KEY, category, changes)?,
+        veto_floor: resolve(ctx, VETO_FLOOR_KEY, category, changes)?,
+    };
+    params
+        .validate()
+        .map_err(|e| format!("{}: {}", governance_profile(category), e))?;
+    Ok(params)
+}
+
+/// Resolves the parameter set for a category: a complete, valid category
+/// override wins, then the global keys, then the built-in defaults.
+pub fn get_governance_params(ctx: &ReducerContext, category: &str) -> (GovernanceParams, String) {
+    if let Some(params) = read_params(ctx, Some(category)) {
+        return (params, governance_profile(Some(category)));
+    }
+
+    let params = read_params(ctx, None).unwrap_or_default();
+    (params, governance_profile(None))
+}
+
+/// `voter_weight` is the total weight of the eligible voter snapshot, which is
+/// its headcount on unweighted ideas. Thresholds never exceed it, so a small
+/// team can still reach quorum. A category's `quorum_override` replaces the
+/// computed quorum.
+pub fn calculate_thresholds(ctx: &ReducerContext, voter_weight: u32, category: &str) -> Thresholds {
+    let (params, profile) = get_governance_params(ctx, category);
+    let reachable = voter_weight.clamp(1, u16::MAX as u32) as u16;
+    let quorum_override = ctx
+        .db
+        .idea_categories()
+        .name()
+        .find(category.to_string())
+        .and_then(|c| c.quorum_override);
+
+    let quorum = quorum_override
+        .unwrap_or_else(|| {
+            ((voter_weight as f64 * params.quorum_ratio).ceil() as u16).max(params.quorum_floor)
+        })
+        .min(reachable);
+    let approval = ((quorum as f64 * params.approval_ratio).ceil() as u16)
+        .max(params.approval_floor)
+        .min(quorum);
+    let veto = ((quorum as f64 * params.veto_ratio).ceil() as u16)
+        .max(params.veto_floor)
+        .min(quorum);
+
+    Thresholds {
+        quorum,
+        approval,
+        veto,
+        profile,
+    }
 }
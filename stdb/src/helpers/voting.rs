@@ -0,0 +1,342 @@
+use spacetimedb::{ReducerContext, ScheduleAt, Table, Timestamp};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::helpers::delegation::resolve_delegated_vote;
+use crate::helpers::governance::{get_governance_veto_window_hours, schedule_governance_execution};
+use crate::helpers::reputation::vote_weight;
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::config::config;
+use crate::tables::idea::{Idea, ideas};
+use crate::tables::idea_eligible_voter::idea_eligible_voters;
+use crate::tables::idea_voting_deadline::{IdeaVotingDeadline, idea_voting_deadlines};
+use crate::tables::vote::votes;
+use crate::types::{IdeaKind, IdeaStatus, VoteType};
+
+/// Vote counts plus their weighted sums. Votes on unweighted ideas carry a
+/// weight of one, so the weighted sums equal the counts. The counts cover
+/// direct votes only; the weighted sums also include `delegated_weight` from
+/// snapshotted voters whose delegation resolves to a direct vote.
+/// `uncast_weight` is the weight still held by snapshotted voters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub up_votes: u16,
+    pub down_votes: u16,
+    pub veto_count: u16,
+    pub total_votes: u16,
+    pub up_weight: u32,
+    pub down_weight: u32,
+    pub veto_weight: u32,
+    pub total_weight: u32,
+    pub delegated_weight: u32,
+    pub uncast_weight: u32,
+}
+
+/// Distributes the weight of snapshotted voters who did not vote directly.
+/// Delegated weight follows up- and down-votes only; a veto stays with the
+/// agent who cast it.
+fn tally_snapshot(
+    ctx: &ReducerContext,
+    idea: &Idea,
+    direct_votes: &HashMap<String, VoteType>,
+    tally: &mut Tally,
+) {
+    let snapshot: Vec<String> = ctx
+        .db
+        .idea_eligible_voters()
+        .by_idea_agent()
+        .filter(&idea.id)
+        .map(|v| v.agent_id)
+        .collect();
+
+    if snapshot.is_empty() {
+        tally.uncast_weight = idea
+            .active_agent_count
+            .saturating_sub(direct_votes.len() as u32);
+        return;
+    }
+
+    for agent in snapshot
+        .iter()
+        .filter(|agent_id| !direct_votes.contains_key(*agent_id))
+        .filter_map(|agent_id| ctx.db.agents().id().find(agent_id))
+    {
+        let weight = vote_weight(ctx, &agent, idea.weighted_voting) as u32;
+        match resolve_delegated_vote(ctx, &agent.id, &idea.category, direct_votes) {
+            Some(VoteType::Up) => tally.up_weight += weight,
+            Some(VoteType::Down) => tally.down_weight += weight,
+            _ => {
+                tally.uncast_weight += weight;
+                continue;
+            }
+        }
+        tally.delegated_weight += weight;
+        tally.total_weight += weight;
+    }
+}
+
+pub fn tally_votes(ctx: &ReducerContext, idea: &Idea) -> Tally {
+    let mut tally = Tally::default();
+    let mut direct_votes = HashMap::new();
+    for vote in ctx.db.votes().by_idea_agent().filter(&idea.id) {
+        let weight = vote.weight as u32;
+        match vote.vote_type {
+            VoteType::Up => {
+                tally.up_votes += 1;
+                tally.up_weight += weight;
+            }
+            VoteType::Down => {
+                tally.down_votes += 1;
+                tally.down_weight += weight;
+            }
+            VoteType::Veto => {
+                tally.veto_count += 1;
+                tally.veto_weight += weight;
+            }
+        }
+        tally.total_votes += 1;
+        tally.total_weight += weight;
+        direct_votes.insert(vote.agent_id, vote.vote_type);
+    }
+    tally_snapshot(ctx, idea, &direct_votes, &mut tally);
+    tally
+}
+
+pub fn get_voting_window_days(ctx: &ReducerContext) -> u64 {
+    ctx.db
+        .config()
+        .key()
+        .find("voting_window_days".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(7)
+}
+
+pub fn voting_ends_at(ctx: &ReducerContext) -> Timestamp {
+    let window_micros = get_voting_window_days(ctx) * 24 * 60 * 60 * 1_000_000;
+    ctx.timestamp + Duration::from_micros(window_micros)
+}
+
+pub fn schedule_voting_deadline(ctx: &ReducerContext, idea_id: u64, ends_at: Timestamp) {
+    ctx.db.idea_voting_deadlines().insert(IdeaVotingDeadline {
+        scheduled_id: 0,
+        idea_id,
+        scheduled_at: ScheduleAt::Time(ends_at),
+    });
+}
+
+pub fn clear_voting_deadlines(ctx: &ReducerContext, idea_id: u64) {
+    let scheduled_ids: Vec<u64> = ctx
+        .db
+        .idea_voting_deadlines()
+        .by_idea_id()
+        .filter(&idea_id)
+        .map(|d| d.scheduled_id)
+        .collect();
+    for scheduled_id in scheduled_ids {
+        ctx.db
+            .idea_voting_deadlines()
+            .scheduled_id()
+            .delete(scheduled_id);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutcomeRules {
+    pub majority_rejection: bool,
+    pub early_rejection: bool,
+}
+
+fn get_config_flag(ctx: &ReducerContext, key: &str, default: bool) -> bool {
+    ctx.db
+        .config()
+        .key()
+        .find(key.to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(default)
+}
+
+pub fn get_outcome_rules(ctx: &ReducerContext) -> OutcomeRules {
+    OutcomeRules {
+        majority_rejection: get_config_flag(ctx, "voting_majority_rejection", true),
+        early_rejection: get_config_flag(ctx, "voting_early_rejection", true),
+    }
+}
+
+/// Decides where an idea stands given its tally. Thresholds are compared
+/// against weighted sums. Rules apply in order: veto, majority down-vote once
+/// quorum is met, approval, and early rejection when the uncast weight of the
+/// voter snapshot can no longer reach quorum or the approval threshold. When
+/// `closing` is set the voting window is over, so an undecided idea expires
+/// without quorum and is rejected otherwise.
+pub fn decide_outcome(
+    idea: &Idea,
+    tally: &Tally,
+    rules: &OutcomeRules,
+    closing: bool,
+) -> (IdeaStatus, &'static str) {
+    let quorum_met = tally.total_weight >= idea.quorum as u32;
+    let uncast = tally.uncast_weight as u64;
+
+    if tally.veto_weight >= idea.veto_threshold as u32 {
+        return (IdeaStatus::Rejected, "by veto");
+    }
+
+    if rules.majority_rejection && quorum_met && tally.down_weight > tally.up_weight {
+        return (IdeaStatus::Rejected, "by majority down-vote");
+    }
+
+    if quorum_met && tally.up_weight >= idea.approval_threshold as u32 {
+        return (IdeaStatus::ApprovedForProject, "");
+    }
+
+    if rules.early_rejection {
+        if tally.total_weight as u64 + uncast < idea.quorum as u64 {
+            return (IdeaStatus::Rejected, "as quorum can no longer be reached");
+        }
+        if tally.up_weight as u64 + uncast < idea.approval_threshold as u64 {
+            return (IdeaStatus::Rejected, "as approval can no longer be reached");
+        }
+    }
+
+    if !closing {
+        (IdeaStatus::Voting, "")
+    } else if !quorum_met {
+        (IdeaStatus::Expired, "quorum not met")
+    } else {
+        (IdeaStatus::Rejected, "at deadline without approval")
+    }
+}
+
+fn announce_outcome(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    title: &str,
+    status: &IdeaStatus,
+    reason: &str,
+    tally: &Tally,
+) -> Result<(), String> {
+    match status {
+        IdeaStatus::Rejected => {
+            send_system_message(ctx, format!("Idea {} rejected {}", idea_id, reason), None)
+        }
+        IdeaStatus::Expired => send_system_message(
+            ctx,
+            format!(
+                "Idea '{}' expired: {} ({} votes)",
+                title, reason, tally.total_votes
+            ),
+            None,
+        ),
+        IdeaStatus::ApprovedForProject => {
+            send_system_message(ctx, format!("Idea '{}' approved", title), Some("general"))?;
+            send_system_message(
+                ctx,
+                format!(
+                    "Idea '{}' approved for project creation. Review and create project when ready.",
+                    title
+                ),
+                Some("zoe"),
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Approved governance proposals are queued for execution rather than handed
+/// to an admin for project creation.
+fn queue_governance_change(ctx: &ReducerContext, idea_id: u64, title: &str) -> Result<(), String> {
+    schedule_governance_execution(ctx, idea_id);
+    let window_hours = get_governance_veto_window_hours(ctx);
+
+    send_system_message(
+        ctx,
+        format!(
+            "Governance proposal '{}' approved; its config changes apply in {} hours",
+            title, window_hours
+        ),
+        Some("general"),
+    )?;
+    send_system_message(
+        ctx,
+        format!(
+            "Governance proposal {} ('{}') approved. Use emergency_veto_governance within {} hours to stop it.",
+            idea_id, title, window_hours
+        ),
+        Some("zoe"),
+    )
+}
+
+fn apply_tally(
+    ctx: &ReducerContext,
+    idea: Idea,
+    tally: Tally,
+    decision: (IdeaStatus, &'static str),
+) -> Result<bool, String> {
+    let (status, reason) = decision;
+    let idea_id = idea.id;
+    let status_changed = status != idea.status;
+    let title = idea.title.clone();
+    let governance = idea.kind == IdeaKind::Governance;
+
+    ctx.db.ideas().id().update(Idea {
+        status: status.clone(),
+        up_votes: tally.up_votes,
+        down_votes: tally.down_votes,
+        veto_count: tally.veto_count,
+        total_votes: tally.total_votes,
+        up_weight: tally.up_weight,
+        down_weight: tally.down_weight,
+        veto_weight: tally.veto_weight,
+        total_weight: tally.total_weight,
+        delegated_weight: tally.delegated_weight,
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+
+    if status_changed {
+        if governance && status == IdeaStatus::ApprovedForProject {
+            queue_governance_change(ctx, idea_id, &title)?;
+        } else {
+            announce_outcome(ctx, idea_id, &title, &status, reason, &tally)?;
+        }
+    }
+
+    Ok(status_changed)
+}
+
+/// Recomputes the idea's counters from its `votes` rows and, while the idea is
+/// still in voting, applies the outcome those counters produce. Ideas in a
+/// funding round are decided when the round closes instead.
+pub fn settle_idea(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+    let tally = tally_votes(ctx, &idea);
+
+    let decision = if idea.status == IdeaStatus::Voting && idea.funding_round_id.is_none() {
+        decide_outcome(&idea, &tally, &get_outcome_rules(ctx), false)
+    } else {
+        (idea.status.clone(), "")
+    };
+
+    if apply_tally(ctx, idea, tally, decision)? {
+        clear_voting_deadlines(ctx, idea_id);
+    }
+    Ok(())
+}
+
+/// Closes voting at the deadline: the idea is approved, rejected, or expired
+/// when quorum was never reached.
+pub fn finalize_idea(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
+    let Some(idea) = ctx.db.ideas().id().find(idea_id) else {
+        return Ok(());
+    };
+
+    if idea.status != IdeaStatus::Voting {
+        return Ok(());
+    }
+
+    let tally = tally_votes(ctx, &idea);
+    let decision = decide_outcome(&idea, &tally, &get_outcome_rules(ctx), true);
+    apply_tally(ctx, idea, tally, decision)?;
+    Ok(())
+}
@@ -4,18 +4,61 @@ pub mod helpers;
 pub mod reducers;
 pub mod tables;
 pub mod types;
+pub mod views;
 
 use crate::helpers::auth::init_zoe_roles;
+use crate::helpers::categories::DEFAULT_IDEA_CATEGORY;
+use crate::helpers::governance::GOVERNANCE_IDEA_CATEGORY;
+use crate::reducers::maintenance::purge::ensure_purge_schedule;
 use crate::tables::agent::{Agent, agents};
 use crate::tables::channel::{Channel, channels};
 use crate::tables::config::{Config, config};
-use crate::types::AgentStatus;
+use crate::tables::idea_category::{IdeaCategory, idea_categories};
+use crate::types::{AgentRole, AgentStatus};
+
+const CONFIG_DEFAULTS: &[(&str, &str)] = &[
+    ("activity_window_days", "7"),
+    ("voting_window_days", "7"),
+    ("voting_majority_rejection", "true"),
+    ("voting_early_rejection", "true"),
+    ("veto_min_role", "zeno"),
+    ("veto_min_reputation", "3"),
+    ("voter_min_age_days", "0"),
+    ("proposer_can_vote", "true"),
+    ("soft_delete_retention_days", "30"),
+    ("notification_retention_days", "30"),
+    ("weighted_voting", "false"),
+    ("vote_weight_zoe", "3"),
+    ("vote_weight_admin", "2"),
+    ("vote_weight_zeno", "1"),
+    ("vote_weight_reputation_tiers", "5:1,20:2"),
+    ("amendment_vote_policy", "flag"),
+    ("idea_sponsors_required", "2"),
+    ("governance_veto_window_hours", "24"),
+    ("discovery_duplicate_threshold", "0.6"),
+    (
+        "discovery_severity_priorities",
+        "low:3,medium:5,high:8,critical:10",
+    ),
+];
+
+const IDEA_CATEGORY_DEFAULTS: &[(&str, &str)] = &[
+    (
+        DEFAULT_IDEA_CATEGORY,
+        "Ideas that do not fit a more specific category",
+    ),
+    (
+        GOVERNANCE_IDEA_CATEGORY,
+        "Proposals that change module config when approved",
+    ),
+];
 
 #[reducer(init)]
 pub fn init(ctx: &ReducerContext) {
     log::info!("Nexus module initializing...");
 
     init_zoe_roles(ctx);
+    ensure_purge_schedule(ctx);
 
     if ctx
         .db
@@ -49,17 +92,34 @@ pub fn init(ctx: &ReducerContext) {
         });
     }
 
-    if ctx
-        .db
-        .config()
-        .key()
-        .find("activity_window_days".to_string())
-        .is_none()
-    {
-        ctx.db.config().insert(Config {
-            key: "activity_window_days".to_string(),
-            value: "7".to_string(),
-        });
+    for (name, description) in IDEA_CATEGORY_DEFAULTS {
+        if ctx
+            .db
+            .idea_categories()
+            .name()
+            .find(name.to_string())
+            .is_none()
+        {
+            ctx.db.idea_categories().insert(IdeaCategory {
+                name: name.to_string(),
+                description: description.to_string(),
+                min_proposer_role: AgentRole::Zeno,
+                quorum_override: None,
+                default_reviewers: Vec::new(),
+                created_by: "system".to_string(),
+                created_at: ctx.timestamp,
+                updated_at: ctx.timestamp,
+            });
+        }
+    }
+
+    for (key, value) in CONFIG_DEFAULTS {
+        if ctx.db.config().key().find(key.to_string()).is_none() {
+            ctx.db.config().insert(Config {
+                key: key.to_string(),
+                value: value.to_string(),
+            });
+        }
     }
 
     log::info!("Default channels and config created");
@@ -69,6 +129,8 @@ pub fn init(ctx: &ReducerContext) {
 pub fn client_connected(ctx: &ReducerContext) {
     log::info!("Client connected: {:?}", ctx.sender());
 
+    ensure_purge_schedule(ctx);
+
     if let Some(agent) = ctx.db.agents().identity().find(ctx.sender()) {
         ctx.db.agents().id().update(Agent {
             status: AgentStatus::Online,
@@ -1,4 +1,5 @@
 pub mod heartbeat;
 pub mod register;
 pub mod set_status;
+pub mod suspend;
 pub mod update_capabilities;
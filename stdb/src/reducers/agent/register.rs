@@ -91,6 +91,8 @@ pub fn register_agent(
         current_task_id: None,
         created_at: ctx.timestamp,
         last_active_at: ctx.timestamp,
+        suspended_at: None,
+        suspension_reason: None,
     });
 
     if ctx
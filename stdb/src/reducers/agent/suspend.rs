@@ -0,0 +1,71 @@
+use spacetimedb::{ReducerContext, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::{Agent, agents};
+use crate::types::AgentRole;
+
+/// Marks an agent as suspended. Until it is reinstated the agent cannot vote,
+/// claim tasks, report discoveries, propose, sponsor, comment or post.
+#[reducer]
+pub fn suspend_agent(ctx: &ReducerContext, agent_id: String, reason: String) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let reason = reason.trim().to_string();
+    if reason.is_empty() {
+        return Err("Suspension reason required".to_string());
+    }
+
+    let agent = ctx
+        .db
+        .agents()
+        .id()
+        .find(agent_id)
+        .ok_or("Agent not found")?;
+
+    if agent.identity == ctx.sender() {
+        return Err("Cannot suspend yourself".to_string());
+    }
+
+    if agent.suspended_at.is_some() {
+        return Err("Agent is already suspended".to_string());
+    }
+
+    let name = agent.name.clone();
+    ctx.db.agents().id().update(Agent {
+        suspended_at: Some(ctx.timestamp),
+        suspension_reason: Some(reason.clone()),
+        ..agent
+    });
+
+    send_system_message(
+        ctx,
+        format!("Agent {} suspended: {}", name, reason),
+        Some("zoe"),
+    )
+}
+
+#[reducer]
+pub fn reinstate_agent(ctx: &ReducerContext, agent_id: String) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let agent = ctx
+        .db
+        .agents()
+        .id()
+        .find(agent_id)
+        .ok_or("Agent not found")?;
+
+    if agent.suspended_at.is_none() {
+        return Err("Agent is not suspended".to_string());
+    }
+
+    let name = agent.name.clone();
+    ctx.db.agents().id().update(Agent {
+        suspended_at: None,
+        suspension_reason: None,
+        ..agent
+    });
+
+    send_system_message(ctx, format!("Agent {} reinstated", name), Some("zoe"))
+}
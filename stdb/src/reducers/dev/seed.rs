@@ -2,11 +2,15 @@ use spacetimedb::{Identity, ReducerContext, Table, reducer};
 use std::time::Duration;
 
 use crate::helpers::auth::require_role;
+use crate::helpers::eligibility::record_eligible_voters;
+use crate::helpers::github::{link_task_github_ref, parse_github_url};
+use crate::helpers::voting::schedule_voting_deadline;
 use crate::tables::agent::{Agent, agents};
 use crate::tables::channel::{Channel, channels};
 use crate::tables::config::{Config, config};
 use crate::tables::discovered_task::{DiscoveredTask, discovered_tasks};
 use crate::tables::idea::{Idea, ideas};
+use crate::tables::idea_category::{IdeaCategory, idea_categories};
 use crate::tables::identity_role::{IdentityRole, identity_roles};
 use crate::tables::message::{Message, messages};
 use crate::tables::project::{Project, projects};
@@ -16,8 +20,8 @@ use crate::tables::task::{Task, tasks};
 use crate::tables::task_dependency::{TaskDependency, task_dependencies};
 use crate::tables::vote::{Vote, votes};
 use crate::types::{
-    AgentRole, AgentStatus, DependencyType, DiscoveredTaskStatus, IdeaStatus, MessageType,
-    ProjectStatus, TaskStatus, VoteType,
+    AgentRole, AgentStatus, DependencyType, DiscoveredTaskStatus, GithubRefKind, IdeaKind,
+    IdeaStatus, MessageType, ProjectStatus, TaskStatus, VoteType,
 };
 
 const DEV_SEED_KEY: &str = "dev_seed_ui_v10";
@@ -126,6 +130,8 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
                 current_task_id: None,
                 created_at: hours_ago((agent_idx as u64 * 48) + 120),
                 last_active_at: hours_ago((agent_idx as u64 * 6) + 2),
+                suspended_at: None,
+                suspension_reason: None,
             });
         }
     }
@@ -226,6 +232,39 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
         ),
     ];
 
+    for category in [
+        "verification",
+        "ops",
+        "protocol",
+        "onboarding",
+        "security",
+        "infrastructure",
+        "docs",
+        "stability",
+        "architecture",
+        "tooling",
+        "process",
+    ] {
+        if ctx
+            .db
+            .idea_categories()
+            .name()
+            .find(category.to_string())
+            .is_none()
+        {
+            ctx.db.idea_categories().insert(IdeaCategory {
+                name: category.to_string(),
+                description: String::new(),
+                min_proposer_role: AgentRole::Zeno,
+                quorum_override: None,
+                default_reviewers: vec!["atlas-admin".to_string()],
+                created_by: "zr-zoe".to_string(),
+                created_at: hours_ago(200),
+                updated_at: hours_ago(200),
+            });
+        }
+    }
+
     let mut idea_ids: Vec<u64> = Vec::new();
     let mut project_ids: Vec<u64> = Vec::new();
     let mut project_task_ids: Vec<Vec<u64>> = Vec::new();
@@ -257,6 +296,24 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
             created_by: "atlas-admin".to_string(),
             created_at: idea_created_at,
             updated_at: idea_updated_at,
+            voting_ends_at: idea_updated_at,
+            governance_profile: Some("default".to_string()),
+            weighted_voting: false,
+            up_weight: 7,
+            down_weight: 1,
+            veto_weight: 0,
+            total_weight: 8,
+            delegated_weight: 0,
+            revision: 1,
+            merged_into_id: None,
+            reviewers: None,
+            discussion_started_at: None,
+            voting_started_at: Some(idea_created_at),
+            sponsor_count: 0,
+            proposed_repo: None,
+            kind: IdeaKind::Project,
+            config_changes: None,
+            funding_round_id: None,
         });
         idea_ids.push(inserted_idea.id);
 
@@ -378,8 +435,20 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
                 created_at: task_created_at,
                 updated_at: task_updated_at,
                 created_by,
+                labels: None,
             });
 
+            for (url, kind) in [
+                (&inserted_task.github_issue_url, GithubRefKind::Issue),
+                (&inserted_task.github_pr_url, GithubRefKind::PullRequest),
+            ] {
+                if let Some(github_ref) =
+                    url.as_deref().and_then(|u| parse_github_url(u, kind).ok())
+                {
+                    link_task_github_ref(ctx, &inserted_task, &github_ref);
+                }
+            }
+
             if is_long_text_task {
                 long_task_ids.push(inserted_task.id);
             }
@@ -707,6 +776,9 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
                 agent_id: (*agent_id).to_string(),
                 vote_type: vote_type.clone(),
                 created_at: hours_ago((idea_offset as u64 * 16) + (vote_idx as u64 * 2) + 28),
+                reason: None,
+                weight: 1,
+                flagged: false,
             });
         }
     }
@@ -729,6 +801,24 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
         created_by: "avalon".to_string(),
         created_at: hours_ago(56),
         updated_at: hours_ago(12),
+        voting_ends_at: ctx.timestamp + Duration::from_secs(72 * 60 * 60),
+        governance_profile: Some("default".to_string()),
+        weighted_voting: false,
+        up_weight: 2,
+        down_weight: 1,
+        veto_weight: 0,
+        total_weight: 3,
+        delegated_weight: 0,
+        revision: 1,
+        merged_into_id: None,
+        reviewers: None,
+        discussion_started_at: None,
+        voting_started_at: Some(hours_ago(56)),
+        sponsor_count: 0,
+        proposed_repo: None,
+        kind: IdeaKind::Project,
+        config_changes: None,
+        funding_round_id: None,
     });
 
     let quorum_met_idea = ctx.db.ideas().insert(Idea {
@@ -749,6 +839,24 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
         created_by: "halley".to_string(),
         created_at: hours_ago(48),
         updated_at: hours_ago(10),
+        voting_ends_at: ctx.timestamp + Duration::from_secs(72 * 60 * 60),
+        governance_profile: Some("default".to_string()),
+        weighted_voting: false,
+        up_weight: 4,
+        down_weight: 2,
+        veto_weight: 0,
+        total_weight: 6,
+        delegated_weight: 0,
+        revision: 1,
+        merged_into_id: None,
+        reviewers: None,
+        discussion_started_at: None,
+        voting_started_at: Some(hours_ago(48)),
+        sponsor_count: 0,
+        proposed_repo: None,
+        kind: IdeaKind::Project,
+        config_changes: None,
+        funding_round_id: None,
     });
 
     let implemented_idea = ctx.db.ideas().insert(Idea {
@@ -769,6 +877,24 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
         created_by: "lyra-admin".to_string(),
         created_at: hours_ago(140),
         updated_at: hours_ago(64),
+        voting_ends_at: hours_ago(64),
+        governance_profile: Some("default".to_string()),
+        weighted_voting: false,
+        up_weight: 9,
+        down_weight: 1,
+        veto_weight: 0,
+        total_weight: 10,
+        delegated_weight: 0,
+        revision: 1,
+        merged_into_id: None,
+        reviewers: None,
+        discussion_started_at: None,
+        voting_started_at: Some(hours_ago(140)),
+        sponsor_count: 0,
+        proposed_repo: None,
+        kind: IdeaKind::Project,
+        config_changes: None,
+        funding_round_id: None,
     });
 
     let rejected_idea = ctx.db.ideas().insert(Idea {
@@ -788,6 +914,24 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
         created_by: "mariana".to_string(),
         created_at: hours_ago(170),
         updated_at: hours_ago(110),
+        voting_ends_at: hours_ago(110),
+        governance_profile: Some("default".to_string()),
+        weighted_voting: false,
+        up_weight: 1,
+        down_weight: 4,
+        veto_weight: 3,
+        total_weight: 8,
+        delegated_weight: 0,
+        revision: 1,
+        merged_into_id: None,
+        reviewers: None,
+        discussion_started_at: None,
+        voting_started_at: Some(hours_ago(170)),
+        sponsor_count: 0,
+        proposed_repo: None,
+        kind: IdeaKind::Project,
+        config_changes: None,
+        funding_round_id: None,
     });
 
     let low_vote_idea_one = ctx.db.ideas().insert(Idea {
@@ -809,6 +953,24 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
         created_by: "mariana".to_string(),
         created_at: hours_ago(30),
         updated_at: hours_ago(8),
+        voting_ends_at: ctx.timestamp + Duration::from_secs(72 * 60 * 60),
+        governance_profile: Some("default".to_string()),
+        weighted_voting: false,
+        up_weight: 1,
+        down_weight: 0,
+        veto_weight: 0,
+        total_weight: 1,
+        delegated_weight: 0,
+        revision: 1,
+        merged_into_id: None,
+        reviewers: None,
+        discussion_started_at: None,
+        voting_started_at: Some(hours_ago(30)),
+        sponsor_count: 0,
+        proposed_repo: None,
+        kind: IdeaKind::Project,
+        config_changes: None,
+        funding_round_id: None,
     });
 
     let low_vote_idea_two = ctx.db.ideas().insert(Idea {
@@ -829,6 +991,24 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
         created_by: "avalon".to_string(),
         created_at: hours_ago(26),
         updated_at: hours_ago(6),
+        voting_ends_at: ctx.timestamp + Duration::from_secs(72 * 60 * 60),
+        governance_profile: Some("default".to_string()),
+        weighted_voting: false,
+        up_weight: 1,
+        down_weight: 1,
+        veto_weight: 0,
+        total_weight: 2,
+        delegated_weight: 0,
+        revision: 1,
+        merged_into_id: None,
+        reviewers: None,
+        discussion_started_at: None,
+        voting_started_at: Some(hours_ago(26)),
+        sponsor_count: 0,
+        proposed_repo: None,
+        kind: IdeaKind::Project,
+        config_changes: None,
+        funding_round_id: None,
     });
 
     let low_vote_idea_three = ctx.db.ideas().insert(Idea {
@@ -850,8 +1030,41 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
         created_by: "halley".to_string(),
         created_at: hours_ago(22),
         updated_at: hours_ago(4),
+        voting_ends_at: ctx.timestamp + Duration::from_secs(72 * 60 * 60),
+        governance_profile: Some("default".to_string()),
+        weighted_voting: false,
+        up_weight: 2,
+        down_weight: 0,
+        veto_weight: 0,
+        total_weight: 2,
+        delegated_weight: 0,
+        revision: 1,
+        merged_into_id: None,
+        reviewers: None,
+        discussion_started_at: None,
+        voting_started_at: Some(hours_ago(22)),
+        sponsor_count: 0,
+        proposed_repo: None,
+        kind: IdeaKind::Project,
+        config_changes: None,
+        funding_round_id: None,
     });
 
+    for idea in [
+        &no_quorum_idea,
+        &quorum_met_idea,
+        &low_vote_idea_one,
+        &low_vote_idea_two,
+        &low_vote_idea_three,
+    ] {
+        let voters = seed_agents
+            .iter()
+            .map(|agent| agent.0.to_string())
+            .collect();
+        record_eligible_voters(ctx, idea.id, voters);
+        schedule_voting_deadline(ctx, idea.id, idea.voting_ends_at);
+    }
+
     let extra_votes = [
         (no_quorum_idea.id, "orion", VoteType::Up),
         (no_quorum_idea.id, "halley", VoteType::Up),
@@ -879,12 +1092,17 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
     ];
 
     for (vote_idx, (idea_id, agent_id, vote_type)) in extra_votes.into_iter().enumerate() {
+        let reason = (vote_type == VoteType::Veto)
+            .then(|| "Overlaps existing operator tooling; no clear owner".to_string());
         ctx.db.votes().insert(Vote {
             id: 0,
             idea_id,
             agent_id: agent_id.to_string(),
             vote_type,
             created_at: hours_ago(20 - (vote_idx as u64 % 10)),
+            reason,
+            weight: 1,
+            flagged: false,
         });
     }
 
@@ -906,6 +1124,9 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
             created_at: hours_ago(9),
             reviewed_at: None,
             reviewed_by: None,
+            duplicate_of: None,
+            blocks_current_task: false,
+            created_idea_id: None,
         });
 
         ctx.db.discovered_tasks().insert(DiscoveredTask {
@@ -925,6 +1146,9 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
             created_at: hours_ago(18),
             reviewed_at: Some(hours_ago(3)),
             reviewed_by: Some("atlas-admin".to_string()),
+            duplicate_of: None,
+            blocks_current_task: false,
+            created_idea_id: None,
         });
 
         ctx.db.discovered_tasks().insert(DiscoveredTask {
@@ -945,6 +1169,9 @@ pub fn seed_ui_data(ctx: &ReducerContext) -> Result<(), String> {
             created_at: hours_ago(36),
             reviewed_at: Some(hours_ago(14)),
             reviewed_by: Some("lyra-admin".to_string()),
+            duplicate_of: None,
+            blocks_current_task: false,
+            created_idea_id: None,
         });
     }
 
@@ -0,0 +1,80 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::retention::{purge_after, require_restorable};
+use crate::tables::agent::agents;
+use crate::tables::deleted_discovered_task::{DeletedDiscoveredTask, deleted_discovered_tasks};
+use crate::tables::discovered_task::discovered_tasks;
+use crate::tables::project::projects;
+use crate::types::AgentRole;
+
+#[reducer]
+pub fn soft_delete_discovered_task(
+    ctx: &ReducerContext,
+    discovery_id: u64,
+    reason: Option<String>,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let discovery = ctx
+        .db
+        .discovered_tasks()
+        .id()
+        .find(discovery_id)
+        .ok_or("Discovery not found")?;
+
+    ctx.db.discovered_tasks().id().delete(discovery_id);
+    ctx.db
+        .deleted_discovered_tasks()
+        .insert(DeletedDiscoveredTask {
+            discovery_id,
+            discovery,
+            reason,
+            deleted_by: sender.id,
+            deleted_at: ctx.timestamp,
+            purge_after: purge_after(ctx),
+        });
+
+    log::info!("Discovery {} soft-deleted", discovery_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn restore_discovered_task(ctx: &ReducerContext, discovery_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let deleted = ctx
+        .db
+        .deleted_discovered_tasks()
+        .discovery_id()
+        .find(discovery_id)
+        .ok_or("Deleted discovery not found")?;
+
+    require_restorable(ctx, deleted.purge_after)?;
+
+    if ctx
+        .db
+        .projects()
+        .id()
+        .find(deleted.discovery.project_id)
+        .is_none()
+    {
+        return Err("Project not found".to_string());
+    }
+
+    ctx.db
+        .deleted_discovered_tasks()
+        .discovery_id()
+        .delete(discovery_id);
+    ctx.db.discovered_tasks().insert(deleted.discovery);
+
+    log::info!("Discovery {} restored", discovery_id);
+    Ok(())
+}
@@ -1,10 +1,15 @@
 use spacetimedb::{ReducerContext, Table, reducer};
 
 use crate::helpers::activity::update_agent_activity;
+use crate::helpers::auth::require_not_suspended;
+use crate::helpers::duplicates::record_duplicate_candidates;
+use crate::helpers::severity::{parse_severity, parse_task_type};
+use crate::reducers::discovered::triage::run_triage_rules;
 use crate::reducers::messaging::send::send_system_message;
 use crate::tables::agent::agents;
 use crate::tables::discovered_task::{DiscoveredTask, discovered_tasks};
 use crate::tables::project::projects;
+use crate::tables::task::tasks;
 use crate::types::DiscoveredTaskStatus;
 
 #[reducer]
@@ -18,6 +23,7 @@ pub fn discover_task(
     priority: u8,
     task_type: String,
     severity: String,
+    blocks_current_task: bool,
 ) -> Result<(), String> {
     let agent = ctx
         .db
@@ -26,10 +32,30 @@ pub fn discover_task(
         .find(ctx.sender())
         .ok_or("Agent not found")?;
 
+    require_not_suspended(&agent)?;
+
     if ctx.db.projects().id().find(project_id).is_none() {
         return Err("Project not found".to_string());
     }
 
+    let current_task = ctx
+        .db
+        .tasks()
+        .id()
+        .find(current_task_id)
+        .ok_or("Current task not found")?;
+
+    if current_task.project_id != project_id {
+        return Err("Current task belongs to a different project".to_string());
+    }
+
+    if current_task.assigned_to.as_deref() != Some(agent.id.as_str()) {
+        return Err("Current task is not assigned to you".to_string());
+    }
+
+    let task_type = parse_task_type(&task_type)?;
+    let severity = parse_severity(&severity)?;
+
     let inserted = ctx.db.discovered_tasks().insert(DiscoveredTask {
         id: 0,
         discovered_by: agent.id.clone(),
@@ -38,17 +64,37 @@ pub fn discover_task(
         title: title.clone(),
         description,
         priority,
-        task_type,
-        severity,
+        task_type: task_type.as_str().to_string(),
+        severity: severity.as_str().to_string(),
         status: DiscoveredTaskStatus::PendingReview,
         created_task_id: None,
         rejection_reason: None,
         created_at: ctx.timestamp,
         reviewed_at: None,
         reviewed_by: None,
+        duplicate_of: None,
+        blocks_current_task,
+        created_idea_id: None,
     });
 
+    let candidates = record_duplicate_candidates(ctx, &inserted);
+
     update_agent_activity(ctx, agent)?;
-    send_system_message(ctx, format!("New discovery: {}", inserted.id), None)?;
+    let message = if candidates.is_empty() {
+        format!("New discovery: {}", inserted.id)
+    } else {
+        let similar = candidates
+            .iter()
+            .map(|c| format!("{} {}", c.candidate_kind.as_str(), c.candidate_id))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "New discovery: {} (possible duplicate of {})",
+            inserted.id, similar
+        )
+    };
+    send_system_message(ctx, message, None)?;
+
+    run_triage_rules(ctx, inserted);
     Ok(())
 }
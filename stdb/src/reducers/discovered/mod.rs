@@ -1,2 +1,4 @@
+pub mod delete;
 pub mod discover;
 pub mod review;
+pub mod triage;
@@ -1,14 +1,63 @@
 use spacetimedb::{ReducerContext, Table, reducer};
 
-use crate::helpers::activity::{count_active_agents, get_activity_window_days};
 use crate::helpers::auth::require_role;
-use crate::helpers::thresholds::calculate_thresholds;
+use crate::helpers::categories::{DEFAULT_IDEA_CATEGORY, require_idea_category};
+use crate::helpers::lineage::record_discovered_from;
+use crate::helpers::severity::severity_priority;
+use crate::reducers::ideas::stages::{new_idea, open_voting};
+use crate::reducers::messaging::notify::notify_agent;
+use crate::reducers::messaging::send_project::send_project_system_message;
 use crate::tables::agent::agents;
 use crate::tables::discovered_task::{DiscoveredTask, discovered_tasks};
-use crate::tables::idea::{Idea, ideas};
+use crate::tables::idea::ideas;
 use crate::tables::project::projects;
 use crate::tables::task::{Task, tasks};
-use crate::types::{AgentRole, DiscoveredTaskStatus, DiscoveryDecision, IdeaStatus, TaskStatus};
+use crate::tables::task_dependency::{TaskDependency, task_dependencies};
+use crate::types::{
+    AgentRole, DependencyType, DiscoveredTaskStatus, DiscoveryDecision, DuplicateTarget,
+    DuplicateTargetKind, Severity, TaskStatus,
+};
+
+/// The original must be in the same project and must not itself be a
+/// duplicate, so duplicate links never chain.
+fn require_duplicate_original(
+    ctx: &ReducerContext,
+    discovery: &DiscoveredTask,
+    target: &DuplicateTarget,
+) -> Result<(), String> {
+    let project_id = match target.kind {
+        DuplicateTargetKind::Discovery => {
+            if target.id == discovery.id {
+                return Err("A discovery cannot duplicate itself".to_string());
+            }
+
+            let original = ctx
+                .db
+                .discovered_tasks()
+                .id()
+                .find(target.id)
+                .ok_or("Original discovery not found")?;
+            if original.status == DiscoveredTaskStatus::Duplicate {
+                return Err("Original discovery is itself a duplicate".to_string());
+            }
+            original.project_id
+        }
+        DuplicateTargetKind::Task => {
+            ctx.db
+                .tasks()
+                .id()
+                .find(target.id)
+                .ok_or("Original task not found")?
+                .project_id
+        }
+    };
+
+    if project_id != discovery.project_id {
+        return Err("Original belongs to a different project".to_string());
+    }
+
+    Ok(())
+}
 
 #[reducer]
 pub fn review_discovered_task(
@@ -16,6 +65,8 @@ pub fn review_discovered_task(
     discovery_id: u64,
     decision: DiscoveryDecision,
     reason: Option<String>,
+    idea_category: Option<String>,
+    duplicate_of: Option<DuplicateTarget>,
 ) -> Result<(), String> {
     require_role(ctx, AgentRole::Admin)?;
 
@@ -33,6 +84,28 @@ pub fn review_discovered_task(
         .find(discovery_id)
         .ok_or("Discovery not found")?;
 
+    apply_discovery_decision(
+        ctx,
+        discovery,
+        decision,
+        &agent.id,
+        reason,
+        idea_category,
+        duplicate_of,
+    )
+}
+
+/// Applies a review decision to a pending discovery, then reports the outcome
+/// in the project's channel and directly to the discoverer.
+pub fn apply_discovery_decision(
+    ctx: &ReducerContext,
+    discovery: DiscoveredTask,
+    decision: DiscoveryDecision,
+    reviewer_id: &str,
+    reason: Option<String>,
+    idea_category: Option<String>,
+    duplicate_of: Option<DuplicateTarget>,
+) -> Result<(), String> {
     if discovery.status != DiscoveredTaskStatus::PendingReview {
         return Err(format!(
             "Discovery already reviewed with status '{}'",
@@ -40,12 +113,26 @@ pub fn review_discovered_task(
         ));
     }
 
-    match decision {
+    let reason = reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+    let discovery_id = discovery.id;
+    let project_id = discovery.project_id;
+    let discovered_by = discovery.discovered_by.clone();
+    let title = discovery.title.clone();
+
+    let outcome = match decision {
         DiscoveryDecision::ApproveAsTask => {
             if ctx.db.projects().id().find(discovery.project_id).is_none() {
                 return Err("Project not found".to_string());
             }
 
+            // Rows stored before severities were validated keep the
+            // submitted priority.
+            let priority = Severity::parse(&discovery.severity)
+                .map(|severity| severity_priority(ctx, &severity))
+                .unwrap_or(discovery.priority);
+
             let inserted = ctx.db.tasks().insert(Task {
                 id: 0,
                 project_id: discovery.project_id,
@@ -56,7 +143,7 @@ pub fn review_discovered_task(
                 claimed_at: None,
                 github_issue_url: None,
                 github_pr_url: None,
-                priority: discovery.priority,
+                priority,
                 source_idea_id: None,
                 review_count: 0,
                 blocked_from_status: None,
@@ -65,58 +152,101 @@ pub fn review_discovered_task(
                 status_changed_at: None,
                 created_at: ctx.timestamp,
                 updated_at: ctx.timestamp,
-                created_by: agent.id.clone(),
+                created_by: reviewer_id.to_string(),
+                labels: None,
             });
 
+            // The originating task may have been deleted while the discovery
+            // waited for review.
+            if let Some(origin) = ctx.db.tasks().id().find(discovery.current_task_id) {
+                record_discovered_from(ctx, inserted.id, origin.id, discovery.id);
+
+                if discovery.blocks_current_task
+                    && !matches!(origin.status, TaskStatus::Completed | TaskStatus::Archived)
+                {
+                    ctx.db.task_dependencies().insert(TaskDependency {
+                        id: 0,
+                        task_id: origin.id,
+                        depends_on_id: inserted.id,
+                        dependency_type: DependencyType::Blocks,
+                        created_at: ctx.timestamp,
+                    });
+                }
+            }
+
             ctx.db.discovered_tasks().id().update(DiscoveredTask {
                 status: DiscoveredTaskStatus::Approved,
                 created_task_id: Some(inserted.id),
                 reviewed_at: Some(ctx.timestamp),
-                reviewed_by: Some(agent.id.clone()),
+                reviewed_by: Some(reviewer_id.to_string()),
                 ..discovery
             });
+
+            format!("approved as task {}", inserted.id)
         }
         DiscoveryDecision::Reject => {
+            let reason = reason.ok_or("Rejection reason required")?;
+
             ctx.db.discovered_tasks().id().update(DiscoveredTask {
                 status: DiscoveredTaskStatus::Rejected,
-                rejection_reason: reason,
+                rejection_reason: Some(reason.clone()),
                 reviewed_at: Some(ctx.timestamp),
-                reviewed_by: Some(agent.id.clone()),
+                reviewed_by: Some(reviewer_id.to_string()),
                 ..discovery
             });
+
+            format!("rejected: {}", reason)
         }
         DiscoveryDecision::EscalateToIdea => {
-            let window_days = get_activity_window_days(ctx);
-            let active_count = count_active_agents(ctx, window_days);
-            let (quorum, approval_threshold, veto_threshold) = calculate_thresholds(active_count);
+            let category = require_idea_category(
+                ctx,
+                idea_category.as_deref().unwrap_or(DEFAULT_IDEA_CATEGORY),
+            )?;
+            let draft = ctx.db.ideas().insert(new_idea(
+                ctx,
+                discovery.title.clone(),
+                discovery.description.clone(),
+                category,
+                discovery.discovered_by.clone(),
+            ));
+            let idea = open_voting(ctx, draft)?;
 
-            ctx.db.ideas().insert(Idea {
-                id: 0,
-                title: discovery.title.clone(),
-                description: discovery.description.clone(),
-                category: discovery.task_type.clone(),
-                status: IdeaStatus::Voting,
-                active_agent_count: active_count,
-                quorum,
-                approval_threshold,
-                veto_threshold,
-                up_votes: 0,
-                down_votes: 0,
-                veto_count: 0,
-                total_votes: 0,
-                created_by: discovery.discovered_by.clone(),
-                created_at: ctx.timestamp,
-                updated_at: ctx.timestamp,
+            ctx.db.discovered_tasks().id().update(DiscoveredTask {
+                status: DiscoveredTaskStatus::EscalatedToIdea,
+                created_idea_id: Some(idea.id),
+                reviewed_at: Some(ctx.timestamp),
+                reviewed_by: Some(reviewer_id.to_string()),
+                ..discovery
             });
 
+            format!("escalated to idea {}", idea.id)
+        }
+        DiscoveryDecision::MarkDuplicate => {
+            let original = duplicate_of.ok_or("Duplicate decision requires the original")?;
+            require_duplicate_original(ctx, &discovery, &original)?;
+            let outcome = format!(
+                "marked as a duplicate of {} {}",
+                original.kind.as_str(),
+                original.id
+            );
+
             ctx.db.discovered_tasks().id().update(DiscoveredTask {
-                status: DiscoveredTaskStatus::EscalatedToIdea,
+                status: DiscoveredTaskStatus::Duplicate,
+                duplicate_of: Some(original),
+                rejection_reason: reason,
                 reviewed_at: Some(ctx.timestamp),
-                reviewed_by: Some(agent.id.clone()),
+                reviewed_by: Some(reviewer_id.to_string()),
                 ..discovery
             });
+
+            outcome
         }
-    }
+    };
+
+    let content = format!("Discovery {} '{}' {}", discovery_id, title, outcome);
+    let context_id = Some(format!("discovery:{}", discovery_id));
+    send_project_system_message(ctx, project_id, content.clone(), context_id.clone())?;
+    notify_agent(ctx, &discovered_by, content, context_id);
 
     Ok(())
 }
@@ -0,0 +1,289 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::reputation::agent_reputation;
+use crate::reducers::discovered::review::apply_discovery_decision;
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::channel::channels;
+use crate::tables::discovered_task::DiscoveredTask;
+use crate::tables::discovery_triage_decision::{
+    DiscoveryTriageDecision, discovery_triage_decisions,
+};
+use crate::tables::discovery_triage_rule::{DiscoveryTriageRule, discovery_triage_rules};
+use crate::tables::project::projects;
+use crate::types::{
+    AgentRole, DiscoveryDecision, Severity, TaskType, TriageAction, TriageConditions,
+};
+
+fn rule_matches(
+    ctx: &ReducerContext,
+    rule: &DiscoveryTriageRule,
+    discovery: &DiscoveredTask,
+) -> bool {
+    let conditions = &rule.conditions;
+
+    if !conditions.task_types.is_empty()
+        && !TaskType::parse(&discovery.task_type)
+            .is_some_and(|t| conditions.task_types.contains(&t))
+    {
+        return false;
+    }
+
+    if !conditions.severities.is_empty()
+        && !Severity::parse(&discovery.severity).is_some_and(|s| conditions.severities.contains(&s))
+    {
+        return false;
+    }
+
+    if !conditions.discoverer_ids.is_empty()
+        && !conditions.discoverer_ids.contains(&discovery.discovered_by)
+    {
+        return false;
+    }
+
+    conditions
+        .min_reputation
+        .is_none_or(|min| agent_reputation(ctx, &discovery.discovered_by) >= min)
+}
+
+/// Checks what the rule's action needs before anything is written, so an
+/// action that cannot run leaves the discovery untouched.
+fn action_ready(
+    ctx: &ReducerContext,
+    rule: &DiscoveryTriageRule,
+    discovery: &DiscoveredTask,
+) -> Result<(), String> {
+    let channel = match rule.action {
+        TriageAction::ApproveAsTask => {
+            if ctx.db.projects().id().find(discovery.project_id).is_none() {
+                return Err("Project not found".to_string());
+            }
+            "general"
+        }
+        TriageAction::Reject => {
+            if rule.reason.is_none() {
+                return Err("Reject rule has no reason".to_string());
+            }
+            "general"
+        }
+        TriageAction::NotifyZoe => "zoe",
+    };
+
+    if ctx
+        .db
+        .channels()
+        .by_name()
+        .filter(&channel.to_string())
+        .next()
+        .is_none()
+    {
+        return Err(format!("Channel not found: {}", channel));
+    }
+
+    Ok(())
+}
+
+/// Applies the first enabled rule of the discovery's project that matches,
+/// in `position` order. The decision is made in the name of the admin who
+/// created the rule and the rule is recorded in `discovery_triage_decisions`.
+/// A rule whose action cannot run is logged and the discovery stays pending
+/// for manual review.
+pub fn run_triage_rules(ctx: &ReducerContext, discovery: DiscoveredTask) {
+    let mut rules: Vec<DiscoveryTriageRule> = ctx
+        .db
+        .discovery_triage_rules()
+        .by_project_id()
+        .filter(&discovery.project_id)
+        .filter(|r| r.enabled)
+        .collect();
+    rules.sort_by_key(|r| (r.position, r.id));
+
+    let Some(rule) = rules.into_iter().find(|r| rule_matches(ctx, r, &discovery)) else {
+        return;
+    };
+
+    let discovery_id = discovery.id;
+    if let Err(error) = action_ready(ctx, &rule, &discovery) {
+        log::warn!(
+            "Triage rule {} skipped for discovery {}: {}",
+            rule.id,
+            discovery_id,
+            error
+        );
+        return;
+    }
+
+    let reason = rule
+        .reason
+        .as_ref()
+        .map(|reason| format!("{} (rule '{}')", reason, rule.name));
+
+    let applied = match rule.action {
+        TriageAction::ApproveAsTask => apply_discovery_decision(
+            ctx,
+            discovery,
+            DiscoveryDecision::ApproveAsTask,
+            &rule.created_by,
+            reason,
+            None,
+            None,
+        ),
+        TriageAction::Reject => apply_discovery_decision(
+            ctx,
+            discovery,
+            DiscoveryDecision::Reject,
+            &rule.created_by,
+            reason,
+            None,
+            None,
+        ),
+        TriageAction::NotifyZoe => send_system_message(
+            ctx,
+            format!(
+                "Discovery {} '{}' ({} {}) flagged by triage rule '{}'",
+                discovery.id, discovery.title, discovery.severity, discovery.task_type, rule.name
+            ),
+            Some("zoe"),
+        ),
+    };
+
+    if let Err(error) = applied {
+        log::warn!(
+            "Triage rule {} failed for discovery {}: {}",
+            rule.id,
+            discovery_id,
+            error
+        );
+        return;
+    }
+
+    ctx.db
+        .discovery_triage_decisions()
+        .insert(DiscoveryTriageDecision {
+            id: 0,
+            discovery_id,
+            rule_id: rule.id,
+            rule_name: rule.name,
+            action: rule.action,
+            created_at: ctx.timestamp,
+        });
+}
+
+fn validate_rule(
+    ctx: &ReducerContext,
+    name: &str,
+    conditions: &TriageConditions,
+    action: &TriageAction,
+    reason: &Option<String>,
+) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Rule name required".to_string());
+    }
+
+    if conditions.task_types.is_empty()
+        && conditions.severities.is_empty()
+        && conditions.min_reputation.is_none()
+        && conditions.discoverer_ids.is_empty()
+    {
+        return Err("A triage rule needs at least one condition".to_string());
+    }
+
+    for agent_id in &conditions.discoverer_ids {
+        if ctx.db.agents().id().find(agent_id.clone()).is_none() {
+            return Err(format!("Agent {} not found", agent_id));
+        }
+    }
+
+    if *action == TriageAction::Reject && reason.is_none() {
+        return Err("Reject rules need a reason".to_string());
+    }
+
+    Ok(())
+}
+
+/// Adds a triage rule to a project. Rules are evaluated by ascending
+/// `position` each time `discover_task` records a discovery in the project.
+#[reducer]
+pub fn create_discovery_triage_rule(
+    ctx: &ReducerContext,
+    project_id: u64,
+    name: String,
+    position: u32,
+    conditions: TriageConditions,
+    action: TriageAction,
+    reason: Option<String>,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    if ctx.db.projects().id().find(project_id).is_none() {
+        return Err("Project not found".to_string());
+    }
+
+    let name = name.trim().to_string();
+    let reason = reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+    validate_rule(ctx, &name, &conditions, &action, &reason)?;
+
+    ctx.db.discovery_triage_rules().insert(DiscoveryTriageRule {
+        id: 0,
+        project_id,
+        name,
+        position,
+        conditions,
+        action,
+        reason,
+        enabled: true,
+        created_by: sender.id,
+        created_at: ctx.timestamp,
+        updated_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn set_discovery_triage_rule_enabled(
+    ctx: &ReducerContext,
+    rule_id: u64,
+    enabled: bool,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let rule = ctx
+        .db
+        .discovery_triage_rules()
+        .id()
+        .find(rule_id)
+        .ok_or("Triage rule not found")?;
+
+    ctx.db
+        .discovery_triage_rules()
+        .id()
+        .update(DiscoveryTriageRule {
+            enabled,
+            updated_at: ctx.timestamp,
+            ..rule
+        });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn delete_discovery_triage_rule(ctx: &ReducerContext, rule_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    if !ctx.db.discovery_triage_rules().id().delete(rule_id) {
+        return Err("Triage rule not found".to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use spacetimedb::{Identity, ReducerContext, Table, reducer};
+
+use crate::helpers::auth::{GITHUB_INTEGRATION_IDENTITY_KEY, require_role};
+use crate::tables::config::{Config, config};
+use crate::types::AgentRole;
+
+#[reducer]
+pub fn set_github_integration_identity(
+    ctx: &ReducerContext,
+    identity: Identity,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Zoe)?;
+
+    let entry = Config {
+        key: GITHUB_INTEGRATION_IDENTITY_KEY.to_string(),
+        value: identity.to_hex().to_string(),
+    };
+
+    if ctx
+        .db
+        .config()
+        .key()
+        .find(GITHUB_INTEGRATION_IDENTITY_KEY.to_string())
+        .is_some()
+    {
+        ctx.db.config().key().update(entry);
+    } else {
+        ctx.db.config().insert(entry);
+    }
+
+    log::info!("GitHub integration identity set to {}", identity.to_hex());
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_github_integration;
+use crate::helpers::github::{GithubRef, find_tasks_by_github_ref, parse_github_repo};
+use crate::reducers::tasks::update_status::apply_task_status;
+use crate::tables::github_event::{GithubEvent, github_events};
+use crate::tables::task::Task;
+use crate::types::{GithubEventKind, GithubEventOutcome, GithubRefKind, TaskStatus};
+
+fn target_status(kind: &GithubEventKind, current: &TaskStatus) -> Option<TaskStatus> {
+    let settled = matches!(current, TaskStatus::Completed | TaskStatus::Archived);
+    match kind {
+        GithubEventKind::PullRequestOpened => Some(TaskStatus::Review),
+        GithubEventKind::PullRequestMerged => Some(TaskStatus::Completed),
+        GithubEventKind::PullRequestClosed if !settled => Some(TaskStatus::Blocked),
+        GithubEventKind::IssueClosed if !settled => Some(TaskStatus::Archived),
+        _ => None,
+    }
+}
+
+fn resolve_tasks(
+    ctx: &ReducerContext,
+    event_ref: &GithubRef,
+    linked_issue_numbers: &[u64],
+) -> Vec<Task> {
+    let mut matched = find_tasks_by_github_ref(ctx, event_ref);
+
+    if event_ref.kind == GithubRefKind::PullRequest {
+        for number in linked_issue_numbers {
+            let issue_ref = GithubRef {
+                repo: event_ref.repo.clone(),
+                kind: GithubRefKind::Issue,
+                number: *number,
+            };
+            for task in find_tasks_by_github_ref(ctx, &issue_ref) {
+                if !matched.iter().any(|t| t.id == task.id) {
+                    matched.push(task);
+                }
+            }
+        }
+    }
+
+    matched
+}
+
+#[reducer]
+pub fn ingest_github_event(
+    ctx: &ReducerContext,
+    kind: GithubEventKind,
+    repo: String,
+    number: u64,
+    linked_issue_numbers: Vec<u64>,
+) -> Result<(), String> {
+    require_github_integration(ctx)?;
+
+    if number == 0 {
+        return Err("Event number must be positive".to_string());
+    }
+
+    let event_ref = GithubRef {
+        repo: parse_github_repo(&repo)?,
+        kind: match kind {
+            GithubEventKind::IssueClosed => GithubRefKind::Issue,
+            _ => GithubRefKind::PullRequest,
+        },
+        number,
+    };
+
+    let matched = resolve_tasks(ctx, &event_ref, &linked_issue_numbers);
+    let task_ids: Vec<u64> = matched.iter().map(|t| t.id).collect();
+    let mut failures = Vec::new();
+
+    for task in matched {
+        let Some(status) = target_status(&kind, &task.status) else {
+            continue;
+        };
+        let task_id = task.id;
+        let github_pr_url = match event_ref.kind {
+            GithubRefKind::PullRequest => Some(event_ref.url()),
+            GithubRefKind::Issue => None,
+        };
+        let archive_reason = match status {
+            TaskStatus::Archived => Some(format!("GitHub issue {} closed", event_ref)),
+            _ => None,
+        };
+
+        if let Err(err) = apply_task_status(ctx, task, status, true, github_pr_url, archive_reason)
+        {
+            failures.push(format!("task {}: {}", task_id, err));
+        }
+    }
+
+    let (outcome, detail) = if task_ids.is_empty() {
+        (GithubEventOutcome::Unmatched, None)
+    } else if failures.is_empty() {
+        (GithubEventOutcome::Applied, None)
+    } else {
+        (GithubEventOutcome::Failed, Some(failures.join("; ")))
+    };
+
+    log::info!(
+        "GitHub event {} for {} {}",
+        kind.as_str(),
+        event_ref,
+        outcome.as_str()
+    );
+
+    ctx.db.github_events().insert(GithubEvent {
+        id: 0,
+        kind,
+        owner: event_ref.repo.owner.clone(),
+        repo: event_ref.repo.repo.clone(),
+        number,
+        linked_issue_numbers,
+        outcome,
+        task_ids,
+        detail,
+        received_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use spacetimedb::{ReducerContext, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::categories::normalize_category_name;
+use crate::helpers::governance::upsert_config;
+use crate::helpers::thresholds::{GovernanceParams, governance_key, governance_profile};
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::config::config;
+use crate::types::AgentRole;
+
+fn normalize_category(category: Option<String>) -> Result<Option<String>, String> {
+    match category {
+        Some(category) => {
+            let category = normalize_category_name(&category);
+            if category.is_empty() {
+                Err("Category cannot be empty".to_string())
+            } else {
+                Ok(Some(category))
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+/// Sets the threshold parameters used for new ideas, either globally or for a
+/// single idea category. Existing ideas keep the thresholds they were created
+/// with.
+#[reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn set_governance_params(
+    ctx: &ReducerContext,
+    category: Option<String>,
+    quorum_ratio: f64,
+    approval_ratio: f64,
+    veto_ratio: f64,
+    quorum_floor: u16,
+    approval_floor: u16,
+    veto_floor: u16,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Zoe)?;
+
+    let category = normalize_category(category)?;
+    let params = GovernanceParams {
+        quorum_ratio,
+        approval_ratio,
+        veto_ratio,
+        quorum_floor,
+        approval_floor,
+        veto_floor,
+    };
+    params.validate()?;
+
+    for (base, value) in params.entries() {
+        upsert_config(ctx, governance_key(base, category.as_deref()), value);
+    }
+
+    send_system_message(
+        ctx,
+        format!(
+            "Governance parameters '{}' set: quorum {}/{}, approval {}/{}, veto {}/{}",
+            governance_profile(category.as_deref()),
+            quorum_ratio,
+            quorum_floor,
+            approval_ratio,
+            approval_floor,
+            veto_ratio,
+            veto_floor
+        ),
+        Some("zoe"),
+    )?;
+
+    Ok(())
+}
+
+/// Removes a category override so the category falls back to the global
+/// parameters.
+#[reducer]
+pub fn clear_governance_params(ctx: &ReducerContext, category: String) -> Result<(), String> {
+    require_role(ctx, AgentRole::Zoe)?;
+
+    let category = normalize_category(Some(category))?;
+    let mut removed = false;
+    for (base, _) in GovernanceParams::default().entries() {
+        removed |= ctx
+            .db
+            .config()
+            .key()
+            .delete(governance_key(base, category.as_deref()));
+    }
+
+    if !removed {
+        return Err("No override for category".to_string());
+    }
+
+    Ok(())
+}
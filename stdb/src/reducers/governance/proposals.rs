@@ -0,0 +1,256 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::activity::update_agent_activity;
+use crate::helpers::auth::{require_not_suspended, require_role};
+use crate::helpers::categories::require_idea_category;
+use crate::helpers::governance::{
+    GOVERNANCE_IDEA_CATEGORY, clear_governance_execution, upsert_config, validate_config_changes,
+};
+use crate::helpers::reputation::meets_role;
+use crate::reducers::ideas::stages::{insert_draft_idea, new_idea};
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::config::config;
+use crate::tables::governance_action::{GovernanceAction, governance_actions};
+use crate::tables::governance_execution::GovernanceExecution;
+use crate::tables::idea::{Idea, ideas};
+use crate::types::{
+    AgentRole, AppliedConfigChange, ConfigChange, GovernanceActionKind, IdeaKind, IdeaStatus,
+};
+
+fn normalize_changes(changes: Vec<ConfigChange>) -> Vec<ConfigChange> {
+    changes
+        .into_iter()
+        .map(|c| ConfigChange {
+            key: c.key.trim().to_string(),
+            value: c.value.trim().to_string(),
+        })
+        .collect()
+}
+
+/// Audit entries for changes that were never written carry no previous value.
+fn unapplied_changes(idea: &Idea) -> Vec<AppliedConfigChange> {
+    idea.config_changes
+        .iter()
+        .flatten()
+        .map(|c| AppliedConfigChange {
+            key: c.key.clone(),
+            previous_value: None,
+            value: c.value.clone(),
+        })
+        .collect()
+}
+
+fn record_governance_action(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    action: GovernanceActionKind,
+    changes: Vec<AppliedConfigChange>,
+    actor: String,
+    reason: Option<String>,
+) {
+    ctx.db.governance_actions().insert(GovernanceAction {
+        id: 0,
+        idea_id,
+        action,
+        changes,
+        actor,
+        reason,
+        created_at: ctx.timestamp,
+    });
+}
+
+/// Proposes config changes as a governance idea. It follows the normal idea
+/// stages in the `governance` category; once approved, the changes are applied
+/// after Zoe's emergency veto window.
+#[reducer]
+pub fn propose_governance_change(
+    ctx: &ReducerContext,
+    title: String,
+    description: String,
+    changes: Vec<ConfigChange>,
+) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    require_not_suspended(&agent)?;
+
+    let changes = normalize_changes(changes);
+    validate_config_changes(ctx, &changes)?;
+
+    let category = require_idea_category(ctx, GOVERNANCE_IDEA_CATEGORY)?;
+    if !meets_role(ctx, &agent, category.min_proposer_role) {
+        return Err(format!(
+            "Proposing in '{}' requires the {} role",
+            category.name,
+            category.min_proposer_role.as_str()
+        ));
+    }
+
+    insert_draft_idea(
+        ctx,
+        Idea {
+            kind: IdeaKind::Governance,
+            config_changes: Some(changes),
+            ..new_idea(ctx, title, description, category, agent.id.clone())
+        },
+    );
+
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
+
+/// Stops an approved governance proposal before its changes are applied.
+#[reducer]
+pub fn emergency_veto_governance(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    reason: String,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Zoe)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let reason = reason.trim().to_string();
+    if reason.is_empty() {
+        return Err("Veto reason required".to_string());
+    }
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    if idea.kind != IdeaKind::Governance {
+        return Err("Idea is not a governance proposal".to_string());
+    }
+
+    if idea.status != IdeaStatus::ApprovedForProject || !clear_governance_execution(ctx, idea_id) {
+        return Err("Governance proposal is not awaiting execution".to_string());
+    }
+
+    let title = idea.title.clone();
+    let changes = unapplied_changes(&idea);
+    ctx.db.ideas().id().update(Idea {
+        status: IdeaStatus::Rejected,
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+
+    record_governance_action(
+        ctx,
+        idea_id,
+        GovernanceActionKind::Vetoed,
+        changes,
+        sender.id,
+        Some(reason.clone()),
+    );
+
+    send_system_message(
+        ctx,
+        format!("Governance proposal '{}' vetoed by Zoe: {}", title, reason),
+        None,
+    )?;
+
+    Ok(())
+}
+
+/// Applies an approved governance proposal once its veto window has passed.
+/// Changes are re-validated first, since categories may have been removed
+/// while the proposal waited; an invalid payload is recorded as `Failed` and
+/// nothing is written.
+#[reducer]
+pub fn execute_governance_change(
+    ctx: &ReducerContext,
+    execution: GovernanceExecution,
+) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Governance changes can only be executed by the scheduler".to_string());
+    }
+
+    let Some(idea) = ctx.db.ideas().id().find(execution.idea_id) else {
+        return Ok(());
+    };
+
+    if idea.kind != IdeaKind::Governance || idea.status != IdeaStatus::ApprovedForProject {
+        return Ok(());
+    }
+
+    let idea_id = idea.id;
+    let title = idea.title.clone();
+
+    if let Err(error) =
+        validate_config_changes(ctx, idea.config_changes.as_deref().unwrap_or_default())
+    {
+        let changes = unapplied_changes(&idea);
+        ctx.db.ideas().id().update(Idea {
+            status: IdeaStatus::Rejected,
+            updated_at: ctx.timestamp,
+            ..idea
+        });
+        record_governance_action(
+            ctx,
+            idea_id,
+            GovernanceActionKind::Failed,
+            changes,
+            "system".to_string(),
+            Some(error.clone()),
+        );
+        return send_system_message(
+            ctx,
+            format!(
+                "Governance proposal '{}' could not be applied: {}",
+                title, error
+            ),
+            None,
+        );
+    }
+
+    let mut applied = Vec::new();
+    for change in idea.config_changes.iter().flatten() {
+        let previous_value = ctx
+            .db
+            .config()
+            .key()
+            .find(change.key.clone())
+            .map(|c| c.value);
+        upsert_config(ctx, change.key.clone(), change.value.clone());
+        applied.push(AppliedConfigChange {
+            key: change.key.clone(),
+            previous_value,
+            value: change.value.clone(),
+        });
+    }
+
+    let summary = applied
+        .iter()
+        .map(|c| format!("{}={}", c.key, c.value))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    ctx.db.ideas().id().update(Idea {
+        status: IdeaStatus::Implemented,
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+    record_governance_action(
+        ctx,
+        idea_id,
+        GovernanceActionKind::Applied,
+        applied,
+        "system".to_string(),
+        None,
+    );
+
+    send_system_message(
+        ctx,
+        format!("Governance proposal '{}' applied: {}", title, summary),
+        None,
+    )
+}
@@ -0,0 +1,147 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::activity::update_agent_activity;
+use crate::helpers::auth::has_role;
+use crate::helpers::voting::settle_idea;
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::config::config;
+use crate::tables::draft_idea::{DraftIdea, draft_ideas};
+use crate::tables::idea::{Idea, ideas};
+use crate::tables::idea_revision::{IdeaRevision, idea_revisions};
+use crate::tables::vote::{Vote, votes};
+use crate::types::{AgentRole, IdeaStatus};
+
+/// What happens to existing votes after a substantive amendment: `reset`
+/// discards them, `flag` keeps them but marks them for the voter to
+/// reaffirm through `change_vote`.
+fn get_amendment_vote_policy(ctx: &ReducerContext) -> String {
+    ctx.db
+        .config()
+        .key()
+        .find("amendment_vote_policy".to_string())
+        .map(|c| c.value)
+        .filter(|v| v == "reset" || v == "flag")
+        .unwrap_or_else(|| "flag".to_string())
+}
+
+fn apply_amendment_vote_policy(ctx: &ReducerContext, idea_id: u64) -> String {
+    let existing: Vec<Vote> = ctx.db.votes().by_idea_agent().filter(&idea_id).collect();
+    let affected = existing.len();
+
+    if get_amendment_vote_policy(ctx) == "reset" {
+        for vote in existing {
+            ctx.db.votes().id().delete(vote.id);
+        }
+        return format!("{} existing votes were reset", affected);
+    }
+
+    for vote in existing {
+        ctx.db.votes().id().update(Vote {
+            flagged: true,
+            ..vote
+        });
+    }
+    format!("{} existing votes need to be reaffirmed", affected)
+}
+
+/// Lets the proposer revise an idea until voting closes. The text being
+/// replaced is kept in `idea_revisions`. Every amendment is substantive unless
+/// an admin marks it otherwise.
+#[reducer]
+pub fn amend_idea(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    title: String,
+    description: String,
+    substantive: bool,
+    note: Option<String>,
+) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    if title.trim().is_empty() {
+        return Err("Title required".to_string());
+    }
+
+    // Drafts are edited in place; revision history starts once the idea is
+    // public.
+    if let Some(draft) = ctx.db.draft_ideas().idea_id().find(idea_id) {
+        if draft.created_by != agent.id {
+            return Err("Only the proposer can amend an idea".to_string());
+        }
+
+        ctx.db.draft_ideas().idea_id().update(DraftIdea {
+            idea: Idea {
+                title,
+                description,
+                updated_at: ctx.timestamp,
+                ..draft.idea
+            },
+            ..draft
+        });
+        return update_agent_activity(ctx, agent);
+    }
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    if idea.created_by != agent.id {
+        return Err("Only the proposer can amend an idea".to_string());
+    }
+
+    if !matches!(idea.status, IdeaStatus::Discussion | IdeaStatus::Voting) {
+        return Err(format!("Cannot amend a {} idea", idea.status.as_str()));
+    }
+
+    if title == idea.title && description == idea.description {
+        return Err("Amendment changes nothing".to_string());
+    }
+
+    // Only admins may pass off a change to the title or description as
+    // cosmetic; voters would otherwise keep backing text they never saw.
+    let substantive = substantive || !has_role(ctx, &ctx.sender(), AgentRole::Admin);
+    let revision = idea.revision;
+    let in_voting = idea.status == IdeaStatus::Voting;
+
+    ctx.db.idea_revisions().insert(IdeaRevision {
+        id: 0,
+        idea_id,
+        revision,
+        title: idea.title.clone(),
+        description: idea.description.clone(),
+        substantive,
+        note,
+        amended_by: agent.id.clone(),
+        created_at: ctx.timestamp,
+    });
+
+    ctx.db.ideas().id().update(Idea {
+        title: title.clone(),
+        description,
+        revision: revision + 1,
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+
+    if substantive && in_voting {
+        let outcome = apply_amendment_vote_policy(ctx, idea_id);
+        send_system_message(
+            ctx,
+            format!(
+                "Idea '{}' amended to revision {}; {}",
+                title,
+                revision + 1,
+                outcome
+            ),
+            None,
+        )?;
+        settle_idea(ctx, idea_id)?;
+    }
+
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
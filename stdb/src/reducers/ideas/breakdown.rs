@@ -0,0 +1,104 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::github::parse_github_repo;
+use crate::helpers::labels::normalize_labels;
+use crate::tables::agent::agents;
+use crate::tables::draft_idea::draft_ideas;
+use crate::tables::idea::{Idea, ideas};
+use crate::tables::idea_proposed_task::{IdeaProposedTask, idea_proposed_tasks};
+use crate::types::IdeaStatus;
+
+/// The author shapes the proposed repo and task breakdown during discussion,
+/// so voters always approve the scope they saw. Drafts have no breakdown yet
+/// because `idea_proposed_tasks` is public.
+fn editable_idea_for_author(ctx: &ReducerContext, idea_id: u64) -> Result<Idea, String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    if ctx.db.draft_ideas().idea_id().find(idea_id).is_some() {
+        return Err("Open discussion before editing the proposed breakdown".to_string());
+    }
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    if idea.created_by != agent.id {
+        return Err("Only the author can edit the proposed breakdown".to_string());
+    }
+
+    if idea.status != IdeaStatus::Discussion {
+        return Err(format!("Cannot edit a {} idea", idea.status.as_str()));
+    }
+
+    Ok(idea)
+}
+
+#[reducer]
+pub fn set_idea_proposed_repo(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    github_repo: Option<String>,
+) -> Result<(), String> {
+    let idea = editable_idea_for_author(ctx, idea_id)?;
+
+    if let Some(repo) = &github_repo {
+        parse_github_repo(repo)?;
+    }
+
+    ctx.db.ideas().id().update(Idea {
+        proposed_repo: github_repo,
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn add_idea_proposed_task(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    title: String,
+    description: String,
+    priority: u8,
+    labels: Vec<String>,
+) -> Result<(), String> {
+    editable_idea_for_author(ctx, idea_id)?;
+
+    if title.trim().is_empty() {
+        return Err("Title required".to_string());
+    }
+
+    ctx.db.idea_proposed_tasks().insert(IdeaProposedTask {
+        id: 0,
+        idea_id,
+        title,
+        description,
+        priority,
+        labels: normalize_labels(labels),
+        created_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_idea_proposed_task(
+    ctx: &ReducerContext,
+    proposed_task_id: u64,
+) -> Result<(), String> {
+    let proposed = ctx
+        .db
+        .idea_proposed_tasks()
+        .id()
+        .find(proposed_task_id)
+        .ok_or("Proposed task not found")?;
+
+    editable_idea_for_author(ctx, proposed.idea_id)?;
+
+    ctx.db.idea_proposed_tasks().id().delete(proposed_task_id);
+    Ok(())
+}
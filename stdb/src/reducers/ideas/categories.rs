@@ -0,0 +1,79 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::categories::normalize_category_name;
+use crate::tables::agent::agents;
+use crate::tables::idea_category::{IdeaCategory, idea_categories};
+use crate::types::AgentRole;
+
+#[reducer]
+pub fn upsert_idea_category(
+    ctx: &ReducerContext,
+    name: String,
+    description: String,
+    min_proposer_role: AgentRole,
+    quorum_override: Option<u16>,
+    default_reviewers: Vec<String>,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let name = normalize_category_name(&name);
+    if name.is_empty() {
+        return Err("Category name required".to_string());
+    }
+
+    if quorum_override == Some(0) {
+        return Err("Quorum override must be at least 1".to_string());
+    }
+
+    for reviewer in &default_reviewers {
+        if ctx.db.agents().id().find(reviewer).is_none() {
+            return Err(format!("Reviewer not found: {}", reviewer));
+        }
+    }
+
+    if let Some(existing) = ctx.db.idea_categories().name().find(&name) {
+        ctx.db.idea_categories().name().update(IdeaCategory {
+            description,
+            min_proposer_role,
+            quorum_override,
+            default_reviewers,
+            updated_at: ctx.timestamp,
+            ..existing
+        });
+    } else {
+        ctx.db.idea_categories().insert(IdeaCategory {
+            name,
+            description,
+            min_proposer_role,
+            quorum_override,
+            default_reviewers,
+            created_by: sender.id,
+            created_at: ctx.timestamp,
+            updated_at: ctx.timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+/// Existing ideas keep their category string; only new proposals are
+/// affected.
+#[reducer]
+pub fn delete_idea_category(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let name = normalize_category_name(&name);
+    if !ctx.db.idea_categories().name().delete(&name) {
+        return Err("Category not found".to_string());
+    }
+
+    Ok(())
+}
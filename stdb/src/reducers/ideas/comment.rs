@@ -0,0 +1,38 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::activity::update_agent_activity;
+use crate::helpers::auth::require_not_suspended;
+use crate::tables::agent::agents;
+use crate::tables::idea::ideas;
+use crate::tables::idea_comment::{IdeaComment, idea_comments};
+
+#[reducer]
+pub fn comment_on_idea(ctx: &ReducerContext, idea_id: u64, content: String) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    require_not_suspended(&agent)?;
+
+    if content.trim().is_empty() {
+        return Err("Comment cannot be empty".to_string());
+    }
+
+    if ctx.db.ideas().id().find(idea_id).is_none() {
+        return Err("Idea not found".to_string());
+    }
+
+    ctx.db.idea_comments().insert(IdeaComment {
+        id: 0,
+        idea_id,
+        agent_id: agent.id.clone(),
+        content,
+        created_at: ctx.timestamp,
+    });
+
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
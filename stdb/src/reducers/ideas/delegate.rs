@@ -0,0 +1,144 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+use std::collections::HashSet;
+
+use crate::helpers::activity::update_agent_activity;
+use crate::helpers::auth::require_not_suspended;
+use crate::helpers::categories::{normalize_category_name, require_idea_category};
+use crate::helpers::delegation::find_delegation;
+use crate::helpers::voting::settle_idea;
+use crate::tables::agent::agents;
+use crate::tables::idea::ideas;
+use crate::tables::vote_delegation::{VoteDelegation, vote_delegations};
+use crate::types::IdeaStatus;
+
+fn normalize_category(
+    ctx: &ReducerContext,
+    category: Option<String>,
+) -> Result<Option<String>, String> {
+    category
+        .map(|c| require_idea_category(ctx, &c).map(|c| c.name))
+        .transpose()
+}
+
+fn find_scoped_delegation(
+    ctx: &ReducerContext,
+    delegator_id: &str,
+    category: &Option<String>,
+) -> Option<VoteDelegation> {
+    ctx.db
+        .vote_delegations()
+        .by_delegator_id()
+        .filter(&delegator_id.to_string())
+        .find(|d| d.category == *category)
+}
+
+fn would_create_cycle(
+    ctx: &ReducerContext,
+    delegator_id: &str,
+    delegate_id: &str,
+    category: &Option<String>,
+) -> bool {
+    let scope = category.as_deref().unwrap_or_default();
+    let mut visited = HashSet::new();
+    let mut current = delegate_id.to_string();
+
+    while visited.insert(current.clone()) {
+        if current == delegator_id {
+            return true;
+        }
+        match find_delegation(ctx, &current, scope) {
+            Some(delegation) => current = delegation.delegate_id,
+            None => return false,
+        }
+    }
+
+    false
+}
+
+/// Re-settles every open idea in the delegation's scope. Delegations chain,
+/// so a change can shift the tally of voters well beyond the two agents.
+fn resettle_open_ideas(ctx: &ReducerContext, category: &Option<String>) -> Result<(), String> {
+    let idea_ids: Vec<u64> = ctx
+        .db
+        .ideas()
+        .by_status()
+        .filter(&IdeaStatus::Voting)
+        .filter(|idea| category.as_ref().is_none_or(|c| *c == idea.category))
+        .map(|idea| idea.id)
+        .collect();
+
+    for idea_id in idea_ids {
+        settle_idea(ctx, idea_id)?;
+    }
+    Ok(())
+}
+
+#[reducer]
+pub fn delegate_vote(
+    ctx: &ReducerContext,
+    delegate_id: String,
+    category: Option<String>,
+) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    require_not_suspended(&agent)?;
+
+    if delegate_id == agent.id {
+        return Err("Cannot delegate to yourself".to_string());
+    }
+
+    if ctx.db.agents().id().find(&delegate_id).is_none() {
+        return Err("Delegate not found".to_string());
+    }
+
+    let category = normalize_category(ctx, category)?;
+
+    if would_create_cycle(ctx, &agent.id, &delegate_id, &category) {
+        return Err("Would create circular delegation".to_string());
+    }
+
+    if let Some(existing) = find_scoped_delegation(ctx, &agent.id, &category) {
+        ctx.db.vote_delegations().id().update(VoteDelegation {
+            delegate_id: delegate_id.clone(),
+            created_at: ctx.timestamp,
+            ..existing
+        });
+    } else {
+        ctx.db.vote_delegations().insert(VoteDelegation {
+            id: 0,
+            delegator_id: agent.id.clone(),
+            delegate_id: delegate_id.clone(),
+            category: category.clone(),
+            created_at: ctx.timestamp,
+        });
+    }
+
+    resettle_open_ideas(ctx, &category)?;
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
+
+#[reducer]
+pub fn revoke_delegation(ctx: &ReducerContext, category: Option<String>) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let category = category.map(|c| normalize_category_name(&c));
+    let delegation =
+        find_scoped_delegation(ctx, &agent.id, &category).ok_or("No delegation to revoke")?;
+
+    ctx.db.vote_delegations().id().delete(delegation.id);
+
+    resettle_open_ideas(ctx, &category)?;
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::retention::{purge_after, require_restorable};
+use crate::helpers::voting::{clear_voting_deadlines, schedule_voting_deadline};
+use crate::tables::agent::agents;
+use crate::tables::deleted_idea::{DeletedIdea, deleted_ideas};
+use crate::tables::draft_idea::draft_ideas;
+use crate::tables::funding_round::funding_rounds;
+use crate::tables::governance_execution::governance_executions;
+use crate::tables::idea::ideas;
+use crate::tables::project::projects;
+use crate::types::{AgentRole, FundingRoundStatus, IdeaStatus};
+
+#[reducer]
+pub fn soft_delete_idea(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    reason: Option<String>,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    // Drafts stay private, so they never reach the public `deleted_ideas`.
+    if ctx.db.draft_ideas().idea_id().find(idea_id).is_some() {
+        return Err("Drafts cannot be deleted; the author can withdraw them".to_string());
+    }
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    if ctx
+        .db
+        .projects()
+        .by_source_idea_id()
+        .filter(&idea_id)
+        .next()
+        .is_some()
+    {
+        return Err("Idea has a project and cannot be deleted".to_string());
+    }
+
+    if ctx
+        .db
+        .governance_executions()
+        .by_idea_id()
+        .filter(&idea_id)
+        .next()
+        .is_some()
+    {
+        return Err("Governance proposal is awaiting execution".to_string());
+    }
+
+    // Its round would close without it and leave it in `Voting` on restore.
+    if idea
+        .funding_round_id
+        .and_then(|round_id| ctx.db.funding_rounds().id().find(round_id))
+        .is_some_and(|round| round.status == FundingRoundStatus::Open)
+    {
+        return Err("Idea is in an open funding round".to_string());
+    }
+
+    clear_voting_deadlines(ctx, idea_id);
+    ctx.db.ideas().id().delete(idea_id);
+    ctx.db.deleted_ideas().insert(DeletedIdea {
+        idea_id,
+        idea,
+        reason,
+        deleted_by: sender.id,
+        deleted_at: ctx.timestamp,
+        purge_after: purge_after(ctx),
+    });
+
+    log::info!("Idea {} soft-deleted", idea_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn restore_idea(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let deleted = ctx
+        .db
+        .deleted_ideas()
+        .idea_id()
+        .find(idea_id)
+        .ok_or("Deleted idea not found")?;
+
+    require_restorable(ctx, deleted.purge_after)?;
+
+    ctx.db.deleted_ideas().idea_id().delete(idea_id);
+    let idea = ctx.db.ideas().insert(deleted.idea);
+
+    // A deadline that passed while the idea was deleted fires right away.
+    if idea.status == IdeaStatus::Voting && idea.funding_round_id.is_none() {
+        schedule_voting_deadline(ctx, idea_id, idea.voting_ends_at);
+    }
+
+    log::info!("Idea {} restored", idea_id);
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use spacetimedb::{ReducerContext, reducer};
+
+use crate::helpers::voting::finalize_idea;
+use crate::tables::idea_voting_deadline::IdeaVotingDeadline;
+
+#[reducer]
+pub fn finalize_idea_voting(
+    ctx: &ReducerContext,
+    deadline: IdeaVotingDeadline,
+) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Voting deadlines can only be finalized by the scheduler".to_string());
+    }
+
+    finalize_idea(ctx, deadline.idea_id)
+}
@@ -0,0 +1,385 @@
+use spacetimedb::{ReducerContext, ScheduleAt, Table, reducer};
+use std::collections::HashMap;
+
+use crate::helpers::activity::{get_activity_window_days, update_agent_activity};
+use crate::helpers::auth::{require_not_suspended, require_role};
+use crate::helpers::eligibility::eligible_agents;
+use crate::helpers::voting::{clear_voting_deadlines, voting_ends_at};
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::funding_allocation::{FundingAllocation, funding_allocations};
+use crate::tables::funding_round::{FundingRound, funding_rounds};
+use crate::tables::funding_round_deadline::{FundingRoundDeadline, funding_round_deadlines};
+use crate::tables::idea::{Idea, ideas};
+use crate::types::{
+    AgentRole, FundingResult, FundingRoundStatus, IdeaKind, IdeaStatus, PointAllocation,
+};
+
+/// Points spent on one idea: linear rounds charge one per point, quadratic
+/// rounds charge the square so concentrated support costs more.
+fn allocation_cost(points: u32, quadratic: bool) -> u64 {
+    if quadratic {
+        points as u64 * points as u64
+    } else {
+        points as u64
+    }
+}
+
+/// Opens a round over ideas that are in voting. Their regular voting is
+/// suspended until the round closes; each eligible agent may then spend
+/// `budget` points across them. The round closes on its own after the voting
+/// window unless an admin closes it first.
+#[reducer]
+pub fn open_funding_round(
+    ctx: &ReducerContext,
+    title: String,
+    idea_ids: Vec<u64>,
+    budget: u32,
+    quadratic: bool,
+    approve_count: u16,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let title = title.trim().to_string();
+    if title.is_empty() {
+        return Err("Round title required".to_string());
+    }
+
+    if budget == 0 {
+        return Err("Budget must be at least 1".to_string());
+    }
+
+    let mut ideas = Vec::new();
+    for idea_id in &idea_ids {
+        if ideas.iter().any(|i: &Idea| i.id == *idea_id) {
+            return Err(format!("Idea {} listed more than once", idea_id));
+        }
+
+        let idea = ctx
+            .db
+            .ideas()
+            .id()
+            .find(*idea_id)
+            .ok_or_else(|| format!("Idea {} not found", idea_id))?;
+
+        if idea.status != IdeaStatus::Voting {
+            return Err(format!("Idea {} is not in voting", idea_id));
+        }
+
+        if idea.kind == IdeaKind::Governance {
+            return Err(format!("Idea {} is a governance proposal", idea_id));
+        }
+
+        if let Some(round_id) = idea.funding_round_id {
+            return Err(format!(
+                "Idea {} is already in funding round {}",
+                idea_id, round_id
+            ));
+        }
+
+        ideas.push(idea);
+    }
+
+    if ideas.len() < 2 {
+        return Err("A funding round needs at least two ideas".to_string());
+    }
+
+    if approve_count == 0 || approve_count as usize >= ideas.len() {
+        return Err(
+            "Approve count must be between 1 and the number of ideas minus one".to_string(),
+        );
+    }
+
+    let closes_at = voting_ends_at(ctx);
+    let round = ctx.db.funding_rounds().insert(FundingRound {
+        id: 0,
+        title,
+        idea_ids,
+        budget,
+        quadratic,
+        approve_count,
+        eligible_agents: eligible_agents(ctx, get_activity_window_days(ctx)),
+        status: FundingRoundStatus::Open,
+        results: Vec::new(),
+        created_by: sender.id,
+        created_at: ctx.timestamp,
+        closed_at: None,
+        closes_at,
+    });
+    ctx.db
+        .funding_round_deadlines()
+        .insert(FundingRoundDeadline {
+            scheduled_id: 0,
+            round_id: round.id,
+            scheduled_at: ScheduleAt::Time(closes_at),
+        });
+
+    for idea in ideas {
+        clear_voting_deadlines(ctx, idea.id);
+        ctx.db.ideas().id().update(Idea {
+            funding_round_id: Some(round.id),
+            updated_at: ctx.timestamp,
+            ..idea
+        });
+    }
+
+    let mode = if round.quadratic {
+        "quadratic"
+    } else {
+        "linear"
+    };
+    send_system_message(
+        ctx,
+        format!(
+            "Funding round '{}' opened over {} ideas: {} {} points per agent, top {} approved",
+            round.title,
+            round.idea_ids.len(),
+            round.budget,
+            mode,
+            round.approve_count
+        ),
+        None,
+    )?;
+
+    Ok(())
+}
+
+/// Replaces the caller's allocation for the round. An empty list withdraws
+/// the caller's points.
+#[reducer]
+pub fn allocate_funding_points(
+    ctx: &ReducerContext,
+    round_id: u64,
+    allocations: Vec<PointAllocation>,
+) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    require_not_suspended(&agent)?;
+
+    let round = ctx
+        .db
+        .funding_rounds()
+        .id()
+        .find(round_id)
+        .ok_or("Funding round not found")?;
+
+    if round.status != FundingRoundStatus::Open {
+        return Err("Funding round is closed".to_string());
+    }
+
+    if !round.eligible_agents.contains(&agent.id) {
+        return Err("Not eligible to allocate in this round".to_string());
+    }
+
+    let allocations: Vec<PointAllocation> =
+        allocations.into_iter().filter(|a| a.points > 0).collect();
+
+    let mut spent: u64 = 0;
+    for (i, allocation) in allocations.iter().enumerate() {
+        if !round.idea_ids.contains(&allocation.idea_id) {
+            return Err(format!("Idea {} is not in this round", allocation.idea_id));
+        }
+
+        if allocations[..i]
+            .iter()
+            .any(|a| a.idea_id == allocation.idea_id)
+        {
+            return Err(format!("Idea {} listed more than once", allocation.idea_id));
+        }
+
+        spent += allocation_cost(allocation.points, round.quadratic);
+    }
+
+    if spent > round.budget as u64 {
+        return Err(format!(
+            "Allocation costs {} points but the budget is {}",
+            spent, round.budget
+        ));
+    }
+
+    let existing: Vec<u64> = ctx
+        .db
+        .funding_allocations()
+        .by_round_agent()
+        .filter((&round_id, &agent.id))
+        .map(|a| a.id)
+        .collect();
+    for id in existing {
+        ctx.db.funding_allocations().id().delete(id);
+    }
+
+    for allocation in allocations {
+        ctx.db.funding_allocations().insert(FundingAllocation {
+            id: 0,
+            round_id,
+            agent_id: agent.id.clone(),
+            idea_id: allocation.idea_id,
+            points: allocation.points,
+            cost: allocation_cost(allocation.points, round.quadratic),
+            created_at: ctx.timestamp,
+        });
+    }
+
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
+
+fn clear_round_deadlines(ctx: &ReducerContext, round_id: u64) {
+    let scheduled_ids: Vec<u64> = ctx
+        .db
+        .funding_round_deadlines()
+        .by_round_id()
+        .filter(&round_id)
+        .map(|d| d.scheduled_id)
+        .collect();
+    for scheduled_id in scheduled_ids {
+        ctx.db
+            .funding_round_deadlines()
+            .scheduled_id()
+            .delete(scheduled_id);
+    }
+}
+
+/// Ranks the round's ideas by points received, then by number of backers,
+/// then by age. The top `approve_count` ideas with any points are approved
+/// and the rest are rejected. Ideas that left voting while the round was open
+/// are skipped.
+fn close_round(ctx: &ReducerContext, round: FundingRound) -> Result<(), String> {
+    let round_id = round.id;
+    clear_round_deadlines(ctx, round_id);
+
+    let mut scores: HashMap<u64, (u64, u32)> = HashMap::new();
+    for allocation in ctx
+        .db
+        .funding_allocations()
+        .by_round_agent()
+        .filter(&round_id)
+    {
+        let entry = scores.entry(allocation.idea_id).or_default();
+        entry.0 += allocation.points as u64;
+        entry.1 += 1;
+    }
+
+    let mut ranked: Vec<Idea> = round
+        .idea_ids
+        .iter()
+        .filter_map(|id| ctx.db.ideas().id().find(*id))
+        .filter(|idea| idea.status == IdeaStatus::Voting && idea.funding_round_id == Some(round_id))
+        .collect();
+    ranked.sort_by(|a, b| {
+        let (a_score, a_backers) = scores.get(&a.id).copied().unwrap_or_default();
+        let (b_score, b_backers) = scores.get(&b.id).copied().unwrap_or_default();
+        b_score
+            .cmp(&a_score)
+            .then(b_backers.cmp(&a_backers))
+            .then(a.id.cmp(&b.id))
+    });
+
+    let mut results = Vec::new();
+    let mut approved_titles = Vec::new();
+    for (i, idea) in ranked.into_iter().enumerate() {
+        let (score, backers) = scores.get(&idea.id).copied().unwrap_or_default();
+        let approved = i < round.approve_count as usize && score > 0;
+        results.push(FundingResult {
+            idea_id: idea.id,
+            rank: i as u32 + 1,
+            score,
+            backers,
+            approved,
+        });
+
+        if approved {
+            approved_titles.push(idea.title.clone());
+        }
+        ctx.db.ideas().id().update(Idea {
+            status: if approved {
+                IdeaStatus::ApprovedForProject
+            } else {
+                IdeaStatus::Rejected
+            },
+            updated_at: ctx.timestamp,
+            ..idea
+        });
+    }
+
+    let title = round.title.clone();
+    let ranked_count = results.len();
+    ctx.db.funding_rounds().id().update(FundingRound {
+        status: FundingRoundStatus::Closed,
+        results,
+        closed_at: Some(ctx.timestamp),
+        ..round
+    });
+
+    let approved = if approved_titles.is_empty() {
+        "none".to_string()
+    } else {
+        approved_titles.join(", ")
+    };
+    send_system_message(
+        ctx,
+        format!(
+            "Funding round '{}' closed: {} ideas ranked, approved {}",
+            title, ranked_count, approved
+        ),
+        Some("general"),
+    )?;
+
+    if !approved_titles.is_empty() {
+        send_system_message(
+            ctx,
+            format!(
+                "Funding round '{}' approved {} for project creation. Review and create projects when ready.",
+                title, approved
+            ),
+            Some("zoe"),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn close_funding_round(ctx: &ReducerContext, round_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let round = ctx
+        .db
+        .funding_rounds()
+        .id()
+        .find(round_id)
+        .ok_or("Funding round not found")?;
+
+    if round.status != FundingRoundStatus::Open {
+        return Err("Funding round is already closed".to_string());
+    }
+
+    close_round(ctx, round)
+}
+
+#[reducer]
+pub fn finalize_funding_round(
+    ctx: &ReducerContext,
+    deadline: FundingRoundDeadline,
+) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Funding rounds can only be finalized by the scheduler".to_string());
+    }
+
+    match ctx.db.funding_rounds().id().find(deadline.round_id) {
+        Some(round) if round.status == FundingRoundStatus::Open => close_round(ctx, round),
+        _ => Ok(()),
+    }
+}
@@ -2,14 +2,9 @@ use spacetimedb::{ReducerContext, reducer};
 
 use crate::helpers::auth::require_role;
 use crate::tables::idea::{Idea, ideas};
-use crate::types::{AgentRole, IdeaStatus};
-
-#[reducer]
-pub fn mark_idea_implemented(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
-    require_role(ctx, AgentRole::Zoe)?;
-
-    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+use crate::types::{AgentRole, IdeaKind, IdeaStatus};
 
+pub fn implement_idea(ctx: &ReducerContext, idea: Idea) -> Result<(), String> {
     if idea.status != IdeaStatus::ApprovedForProject {
         return Err("Idea must be approved for project before marking as implemented".to_string());
     }
@@ -22,3 +17,16 @@ pub fn mark_idea_implemented(ctx: &ReducerContext, idea_id: u64) -> Result<(), S
 
     Ok(())
 }
+
+#[reducer]
+pub fn mark_idea_implemented(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Zoe)?;
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    if idea.kind == IdeaKind::Governance {
+        return Err("Governance proposals are implemented when their changes apply".to_string());
+    }
+
+    implement_idea(ctx, idea)
+}
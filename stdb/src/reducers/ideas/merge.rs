@@ -0,0 +1,105 @@
+use spacetimedb::{ReducerContext, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::eligibility::is_eligible_voter;
+use crate::helpers::reputation::vote_weight;
+use crate::helpers::voting::{clear_voting_deadlines, settle_idea};
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::idea::{Idea, ideas};
+use crate::tables::vote::{Vote, votes};
+use crate::types::{AgentRole, IdeaStatus};
+
+/// Closes a duplicate idea as `Merged` into the canonical one. Votes move
+/// across when the voter is eligible on the canonical idea and has not voted
+/// there; conflicting votes stay on the duplicate.
+#[reducer]
+pub fn merge_ideas(
+    ctx: &ReducerContext,
+    duplicate_id: u64,
+    canonical_id: u64,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    if duplicate_id == canonical_id {
+        return Err("Cannot merge an idea into itself".to_string());
+    }
+
+    let duplicate = ctx
+        .db
+        .ideas()
+        .id()
+        .find(duplicate_id)
+        .ok_or("Duplicate idea not found")?;
+    let canonical = ctx
+        .db
+        .ideas()
+        .id()
+        .find(canonical_id)
+        .ok_or("Canonical idea not found")?;
+
+    if duplicate.status != IdeaStatus::Voting {
+        return Err("Duplicate idea is not in voting".to_string());
+    }
+
+    if canonical.status != IdeaStatus::Voting {
+        return Err("Canonical idea is not in voting".to_string());
+    }
+
+    if duplicate.funding_round_id.is_some() || canonical.funding_round_id.is_some() {
+        return Err("Ideas in a funding round cannot be merged".to_string());
+    }
+
+    let duplicate_votes: Vec<Vote> = ctx
+        .db
+        .votes()
+        .by_idea_agent()
+        .filter(&duplicate_id)
+        .collect();
+
+    let mut migrated = 0;
+    for vote in duplicate_votes {
+        let conflicts = ctx
+            .db
+            .votes()
+            .by_idea_agent()
+            .filter(&canonical_id)
+            .any(|v| v.agent_id == vote.agent_id);
+
+        if conflicts || !is_eligible_voter(ctx, canonical_id, &vote.agent_id) {
+            continue;
+        }
+
+        let Some(voter) = ctx.db.agents().id().find(&vote.agent_id) else {
+            continue;
+        };
+
+        ctx.db.votes().id().update(Vote {
+            idea_id: canonical_id,
+            weight: vote_weight(ctx, &voter, canonical.weighted_voting),
+            ..vote
+        });
+        migrated += 1;
+    }
+
+    let duplicate_title = duplicate.title.clone();
+    ctx.db.ideas().id().update(Idea {
+        status: IdeaStatus::Merged,
+        merged_into_id: Some(canonical_id),
+        updated_at: ctx.timestamp,
+        ..duplicate
+    });
+    clear_voting_deadlines(ctx, duplicate_id);
+
+    send_system_message(
+        ctx,
+        format!(
+            "Idea '{}' merged into '{}' ({} votes migrated)",
+            duplicate_title, canonical.title, migrated
+        ),
+        None,
+    )?;
+
+    settle_idea(ctx, duplicate_id)?;
+    settle_idea(ctx, canonical_id)
+}
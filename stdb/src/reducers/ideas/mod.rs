@@ -1,3 +1,16 @@
+pub mod amend;
+pub mod breakdown;
+pub mod categories;
+pub mod comment;
+pub mod delegate;
+pub mod delete;
+pub mod finalize;
+pub mod funding;
 pub mod implement;
+pub mod merge;
+pub mod override_veto;
 pub mod propose;
+pub mod recount;
+pub mod stages;
 pub mod vote;
+pub mod withdraw;
@@ -0,0 +1,83 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::voting::{schedule_voting_deadline, settle_idea, voting_ends_at};
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::idea::{Idea, ideas};
+use crate::tables::veto_override::{VetoOverride, veto_overrides};
+use crate::tables::vote::{Vote, votes};
+use crate::types::{AgentRole, IdeaStatus, VoteType};
+
+/// Discards the vetoes that rejected an idea and reopens it for a fresh voting
+/// window. The discarded votes are kept on the `veto_overrides` audit row.
+#[reducer]
+pub fn override_veto(ctx: &ReducerContext, idea_id: u64, reason: String) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let reason = reason.trim().to_string();
+    if reason.is_empty() {
+        return Err("Override reason required".to_string());
+    }
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    if idea.status != IdeaStatus::Rejected {
+        return Err("Idea is not rejected".to_string());
+    }
+
+    let vetoes: Vec<Vote> = ctx
+        .db
+        .votes()
+        .by_idea_agent()
+        .filter(&idea_id)
+        .filter(|v| v.vote_type == VoteType::Veto)
+        .collect();
+
+    let veto_weight: u32 = vetoes.iter().map(|v| v.weight as u32).sum();
+    if veto_weight < idea.veto_threshold as u32 {
+        return Err("Idea was not rejected by veto".to_string());
+    }
+
+    for vote in &vetoes {
+        ctx.db.votes().id().delete(vote.id);
+    }
+
+    let veto_count = vetoes.len();
+    ctx.db.veto_overrides().insert(VetoOverride {
+        id: 0,
+        idea_id,
+        overridden_by: sender.id.clone(),
+        reason: reason.clone(),
+        vetoes,
+        created_at: ctx.timestamp,
+    });
+
+    let ends_at = voting_ends_at(ctx);
+    let title = idea.title.clone();
+    ctx.db.ideas().id().update(Idea {
+        status: IdeaStatus::Voting,
+        voting_ends_at: ends_at,
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+    schedule_voting_deadline(ctx, idea_id, ends_at);
+
+    send_system_message(
+        ctx,
+        format!(
+            "{} vetoes on idea '{}' overridden by {}: {}. Voting reopened.",
+            veto_count, title, sender.name, reason
+        ),
+        None,
+    )?;
+
+    settle_idea(ctx, idea_id)
+}
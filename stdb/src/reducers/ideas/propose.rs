@@ -1,14 +1,14 @@
-use spacetimedb::{ReducerContext, Table, reducer};
+use spacetimedb::{ReducerContext, reducer};
 
-use crate::helpers::activity::{
-    count_active_agents, get_activity_window_days, update_agent_activity,
-};
-use crate::helpers::thresholds::calculate_thresholds;
-use crate::reducers::messaging::send::send_system_message;
+use crate::helpers::activity::update_agent_activity;
+use crate::helpers::auth::require_not_suspended;
+use crate::helpers::categories::require_idea_category;
+use crate::helpers::reputation::meets_role;
+use crate::reducers::ideas::stages::{insert_draft_idea, new_idea};
 use crate::tables::agent::agents;
-use crate::tables::idea::{Idea, ideas};
-use crate::types::IdeaStatus;
 
+/// Creates the idea as a `Draft`; the author opens discussion when it is
+/// ready for other agents.
 #[reducer]
 pub fn propose_idea(
     ctx: &ReducerContext,
@@ -23,30 +23,22 @@ pub fn propose_idea(
         .find(ctx.sender())
         .ok_or("Agent not found")?;
 
-    let window_days = get_activity_window_days(ctx);
-    let active_count = count_active_agents(ctx, window_days);
-    let (quorum, approval_threshold, veto_threshold) = calculate_thresholds(active_count);
+    require_not_suspended(&agent)?;
 
-    let inserted = ctx.db.ideas().insert(Idea {
-        id: 0,
-        title: title.clone(),
-        description,
-        category,
-        status: IdeaStatus::Voting,
-        active_agent_count: active_count,
-        quorum,
-        approval_threshold,
-        veto_threshold,
-        up_votes: 0,
-        down_votes: 0,
-        veto_count: 0,
-        total_votes: 0,
-        created_by: agent.id.clone(),
-        created_at: ctx.timestamp,
-        updated_at: ctx.timestamp,
-    });
+    let category = require_idea_category(ctx, &category)?;
+    if !meets_role(ctx, &agent, category.min_proposer_role) {
+        return Err(format!(
+            "Proposing in '{}' requires the {} role",
+            category.name,
+            category.min_proposer_role.as_str()
+        ));
+    }
+
+    insert_draft_idea(
+        ctx,
+        new_idea(ctx, title, description, category, agent.id.clone()),
+    );
 
     update_agent_activity(ctx, agent)?;
-    send_system_message(ctx, format!("New idea proposed: {}", inserted.id), None)?;
     Ok(())
 }
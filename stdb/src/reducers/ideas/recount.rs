@@ -0,0 +1,25 @@
+use spacetimedb::{ReducerContext, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::voting::{settle_idea, tally_votes};
+use crate::tables::idea::ideas;
+use crate::types::AgentRole;
+
+#[reducer]
+pub fn recount_idea(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+    let tally = tally_votes(ctx, &idea);
+
+    if tally.total_votes != idea.total_votes {
+        log::warn!(
+            "Idea {} counter drift: stored {} votes, counted {}",
+            idea_id,
+            idea.total_votes,
+            tally.total_votes
+        );
+    }
+
+    settle_idea(ctx, idea_id)
+}
@@ -0,0 +1,269 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::activity::{get_activity_window_days, update_agent_activity};
+use crate::helpers::auth::require_not_suspended;
+use crate::helpers::eligibility::{eligible_voters, record_eligible_voters, snapshot_weight};
+use crate::helpers::reputation::weighted_voting_enabled;
+use crate::helpers::thresholds::calculate_thresholds;
+use crate::helpers::voting::{schedule_voting_deadline, voting_ends_at};
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::config::config;
+use crate::tables::draft_idea::{DraftIdea, draft_ideas};
+use crate::tables::idea::{Idea, ideas};
+use crate::tables::idea_category::IdeaCategory;
+use crate::tables::idea_sponsor::{IdeaSponsor, idea_sponsors};
+use crate::types::{IdeaKind, IdeaStatus};
+
+pub fn get_idea_sponsors_required(ctx: &ReducerContext) -> u16 {
+    ctx.db
+        .config()
+        .key()
+        .find("idea_sponsors_required".to_string())
+        .and_then(|c| c.value.parse().ok())
+        .unwrap_or(2)
+}
+
+/// Stages move strictly forward: Draft -> Discussion -> Voting.
+fn is_valid_stage_transition(current: &IdeaStatus, next: &IdeaStatus) -> bool {
+    matches!(
+        (current, next),
+        (IdeaStatus::Draft, IdeaStatus::Discussion) | (IdeaStatus::Discussion, IdeaStatus::Voting)
+    )
+}
+
+fn require_stage_transition(idea: &Idea, next: &IdeaStatus) -> Result<(), String> {
+    if is_valid_stage_transition(&idea.status, next) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid transition: {} -> {}",
+            idea.status.as_str(),
+            next.as_str()
+        ))
+    }
+}
+
+fn announce_reviewers(ctx: &ReducerContext, idea: &Idea) -> Result<(), String> {
+    let Some(reviewers) = idea.reviewers.as_ref().filter(|r| !r.is_empty()) else {
+        return Ok(());
+    };
+
+    send_system_message(
+        ctx,
+        format!(
+            "Idea {} in '{}' awaits review from {}",
+            idea.id,
+            idea.category,
+            reviewers.join(", ")
+        ),
+        None,
+    )
+}
+
+/// Builds a new idea in `Draft`. Voting thresholds stay empty until the idea
+/// reaches `Voting`.
+pub fn new_idea(
+    ctx: &ReducerContext,
+    title: String,
+    description: String,
+    category: IdeaCategory,
+    created_by: String,
+) -> Idea {
+    Idea {
+        id: 0,
+        title,
+        description,
+        category: category.name,
+        status: IdeaStatus::Draft,
+        active_agent_count: 0,
+        quorum: 0,
+        approval_threshold: 0,
+        veto_threshold: 0,
+        up_votes: 0,
+        down_votes: 0,
+        veto_count: 0,
+        total_votes: 0,
+        created_by,
+        created_at: ctx.timestamp,
+        updated_at: ctx.timestamp,
+        voting_ends_at: ctx.timestamp,
+        governance_profile: None,
+        weighted_voting: false,
+        up_weight: 0,
+        down_weight: 0,
+        veto_weight: 0,
+        total_weight: 0,
+        delegated_weight: 0,
+        revision: 1,
+        merged_into_id: None,
+        reviewers: Some(category.default_reviewers),
+        discussion_started_at: None,
+        voting_started_at: None,
+        sponsor_count: 0,
+        proposed_repo: None,
+        kind: IdeaKind::Project,
+        config_changes: None,
+        funding_round_id: None,
+    }
+}
+
+/// Stores a draft where only its author can read it. The row goes through
+/// `ideas` to draw its id and leaves again in the same transaction, so
+/// subscribers never see it.
+pub fn insert_draft_idea(ctx: &ReducerContext, idea: Idea) {
+    let idea = ctx.db.ideas().insert(idea);
+    ctx.db.ideas().id().delete(idea.id);
+
+    ctx.db.draft_ideas().insert(DraftIdea {
+        idea_id: idea.id,
+        created_by: idea.created_by.clone(),
+        idea,
+    });
+}
+
+/// Opens voting: snapshots eligible voters, derives thresholds from that
+/// snapshot's total weight and schedules the voting deadline.
+pub fn open_voting(ctx: &ReducerContext, idea: Idea) -> Result<Idea, String> {
+    if idea.status != IdeaStatus::Draft {
+        require_stage_transition(&idea, &IdeaStatus::Voting)?;
+    }
+
+    let window_days = get_activity_window_days(ctx);
+    let voters = eligible_voters(ctx, window_days, &idea.created_by);
+    let active_count = voters.len() as u32;
+    let weighted = weighted_voting_enabled(ctx);
+    let thresholds =
+        calculate_thresholds(ctx, snapshot_weight(ctx, &voters, weighted), &idea.category);
+    let ends_at = voting_ends_at(ctx);
+
+    let opened = ctx.db.ideas().id().update(Idea {
+        status: IdeaStatus::Voting,
+        active_agent_count: active_count,
+        quorum: thresholds.quorum,
+        approval_threshold: thresholds.approval,
+        veto_threshold: thresholds.veto,
+        voting_ends_at: ends_at,
+        governance_profile: Some(thresholds.profile),
+        weighted_voting: weighted,
+        voting_started_at: Some(ctx.timestamp),
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+
+    record_eligible_voters(ctx, opened.id, voters);
+    schedule_voting_deadline(ctx, opened.id, ends_at);
+
+    send_system_message(
+        ctx,
+        format!("Voting opened on idea '{}'", opened.title),
+        None,
+    )?;
+    if opened.discussion_started_at.is_none() {
+        announce_reviewers(ctx, &opened)?;
+    }
+
+    Ok(opened)
+}
+
+#[reducer]
+pub fn open_idea_discussion(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let draft = ctx
+        .db
+        .draft_ideas()
+        .idea_id()
+        .find(idea_id)
+        .ok_or("Draft not found")?;
+
+    if draft.created_by != agent.id {
+        return Err("Only the author can open discussion".to_string());
+    }
+
+    require_stage_transition(&draft.idea, &IdeaStatus::Discussion)?;
+
+    ctx.db.draft_ideas().idea_id().delete(idea_id);
+    let opened = ctx.db.ideas().insert(Idea {
+        status: IdeaStatus::Discussion,
+        discussion_started_at: Some(ctx.timestamp),
+        updated_at: ctx.timestamp,
+        ..draft.idea
+    });
+
+    send_system_message(
+        ctx,
+        format!(
+            "Idea '{}' open for discussion; {} sponsors needed to start voting",
+            opened.title,
+            get_idea_sponsors_required(ctx)
+        ),
+        None,
+    )?;
+    announce_reviewers(ctx, &opened)?;
+
+    if get_idea_sponsors_required(ctx) == 0 {
+        open_voting(ctx, opened)?;
+    }
+
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
+
+#[reducer]
+pub fn sponsor_idea(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    require_not_suspended(&agent)?;
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    if idea.status != IdeaStatus::Discussion {
+        return Err("Idea is not in discussion".to_string());
+    }
+
+    if idea.created_by == agent.id {
+        return Err("Cannot sponsor your own idea".to_string());
+    }
+
+    if ctx
+        .db
+        .idea_sponsors()
+        .by_idea_id()
+        .filter(&idea_id)
+        .any(|s| s.agent_id == agent.id)
+    {
+        return Err("Already sponsored".to_string());
+    }
+
+    ctx.db.idea_sponsors().insert(IdeaSponsor {
+        id: 0,
+        idea_id,
+        agent_id: agent.id.clone(),
+        created_at: ctx.timestamp,
+    });
+
+    let sponsor_count = idea.sponsor_count + 1;
+    let idea = ctx.db.ideas().id().update(Idea {
+        sponsor_count,
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+
+    if sponsor_count >= get_idea_sponsors_required(ctx) {
+        open_voting(ctx, idea)?;
+    }
+
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
@@ -1,14 +1,16 @@
 use spacetimedb::{ReducerContext, Table, reducer};
 
 use crate::helpers::activity::update_agent_activity;
-use crate::reducers::messaging::send::send_system_message;
-use crate::tables::agent::agents;
+use crate::helpers::auth::require_not_suspended;
+use crate::helpers::eligibility::is_eligible_voter;
+use crate::helpers::reputation::{require_veto_rights, vote_weight};
+use crate::helpers::voting::settle_idea;
+use crate::tables::agent::{Agent, agents};
 use crate::tables::idea::{Idea, ideas};
 use crate::tables::vote::{Vote, votes};
 use crate::types::{IdeaStatus, VoteType};
 
-#[reducer]
-pub fn vote_idea(ctx: &ReducerContext, idea_id: u64, vote_type: VoteType) -> Result<(), String> {
+fn open_idea_for_agent(ctx: &ReducerContext, idea_id: u64) -> Result<(Agent, Idea), String> {
     let agent = ctx
         .db
         .agents()
@@ -16,20 +18,69 @@ pub fn vote_idea(ctx: &ReducerContext, idea_id: u64, vote_type: VoteType) -> Res
         .find(ctx.sender())
         .ok_or("Agent not found")?;
 
+    require_not_suspended(&agent)?;
+
     let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
 
     if idea.status != IdeaStatus::Voting {
         return Err("Voting closed".to_string());
     }
 
-    let existing = ctx
-        .db
+    if let Some(round_id) = idea.funding_round_id {
+        return Err(format!(
+            "Idea is being prioritized in funding round {}",
+            round_id
+        ));
+    }
+
+    if !is_eligible_voter(ctx, idea_id, &agent.id) {
+        return Err("Not eligible to vote on this idea".to_string());
+    }
+
+    Ok((agent, idea))
+}
+
+fn find_vote(ctx: &ReducerContext, idea_id: u64, agent_id: &str) -> Option<Vote> {
+    ctx.db
         .votes()
         .by_idea_agent()
         .filter(&idea_id)
-        .any(|v| v.agent_id == agent.id);
+        .find(|v| v.agent_id == agent_id)
+}
+
+/// Vetoes need veto rights and a reason; other votes may carry one.
+fn validate_reason(
+    ctx: &ReducerContext,
+    agent: &Agent,
+    vote_type: &VoteType,
+    reason: Option<String>,
+) -> Result<Option<String>, String> {
+    let reason = reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+
+    if *vote_type == VoteType::Veto {
+        require_veto_rights(ctx, agent)?;
+        if reason.is_none() {
+            return Err("Veto requires a reason".to_string());
+        }
+    }
+
+    Ok(reason)
+}
+
+#[reducer]
+pub fn vote_idea(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    vote_type: VoteType,
+    reason: Option<String>,
+) -> Result<(), String> {
+    let (agent, idea) = open_idea_for_agent(ctx, idea_id)?;
+    let reason = validate_reason(ctx, &agent, &vote_type, reason)?;
+    let weight = vote_weight(ctx, &agent, idea.weighted_voting);
 
-    if existing {
+    if find_vote(ctx, idea_id, &agent.id).is_some() {
         return Err("Already voted".to_string());
     }
 
@@ -37,84 +88,56 @@ pub fn vote_idea(ctx: &ReducerContext, idea_id: u64, vote_type: VoteType) -> Res
         id: 0,
         idea_id,
         agent_id: agent.id.clone(),
-        vote_type: vote_type.clone(),
+        vote_type,
         created_at: ctx.timestamp,
+        reason,
+        weight,
+        flagged: false,
     });
 
-    let (up_votes, down_votes, veto_count) = match vote_type {
-        VoteType::Up => (idea.up_votes + 1, idea.down_votes, idea.veto_count),
-        VoteType::Down => (idea.up_votes, idea.down_votes + 1, idea.veto_count),
-        VoteType::Veto => (idea.up_votes, idea.down_votes, idea.veto_count + 1),
-    };
-    let total_votes = idea.total_votes + 1;
-
-    if veto_count >= idea.veto_threshold {
-        ctx.db.ideas().id().update(Idea {
-            status: IdeaStatus::Rejected,
-            up_votes,
-            down_votes,
-            veto_count,
-            total_votes,
-            updated_at: ctx.timestamp,
-            ..idea
-        });
-        update_agent_activity(ctx, agent)?;
-        send_system_message(ctx, format!("Idea {} rejected by veto", idea_id), None)?;
-        return Ok(());
-    }
+    settle_idea(ctx, idea_id)?;
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
 
-    if total_votes < idea.quorum {
-        ctx.db.ideas().id().update(Idea {
-            up_votes,
-            down_votes,
-            veto_count,
-            total_votes,
-            updated_at: ctx.timestamp,
-            ..idea
-        });
-        update_agent_activity(ctx, agent)?;
-        return Ok(());
-    }
+#[reducer]
+pub fn change_vote(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    vote_type: VoteType,
+    reason: Option<String>,
+) -> Result<(), String> {
+    let (agent, idea) = open_idea_for_agent(ctx, idea_id)?;
+
+    let vote = find_vote(ctx, idea_id, &agent.id).ok_or("No vote to change")?;
 
-    if up_votes >= idea.approval_threshold {
-        let idea_title = idea.title.clone();
-
-        ctx.db.ideas().id().update(Idea {
-            status: IdeaStatus::ApprovedForProject,
-            up_votes,
-            down_votes,
-            veto_count,
-            total_votes,
-            updated_at: ctx.timestamp,
-            ..idea
-        });
-        update_agent_activity(ctx, agent)?;
-
-        send_system_message(
-            ctx,
-            format!("Idea '{}' approved", idea_title),
-            Some("general"),
-        )?;
-        send_system_message(
-            ctx,
-            format!(
-                "Idea '{}' approved for project creation. Review and create project when ready.",
-                idea_title
-            ),
-            Some("zoe"),
-        )?;
-        return Ok(());
+    if vote.vote_type == vote_type && !vote.flagged {
+        return Err(format!("Vote is already '{}'", vote_type.as_str()));
     }
 
-    ctx.db.ideas().id().update(Idea {
-        up_votes,
-        down_votes,
-        veto_count,
-        total_votes,
-        updated_at: ctx.timestamp,
-        ..idea
+    let reason = validate_reason(ctx, &agent, &vote_type, reason)?;
+    ctx.db.votes().id().update(Vote {
+        vote_type,
+        reason,
+        weight: vote_weight(ctx, &agent, idea.weighted_voting),
+        flagged: false,
+        ..vote
     });
 
+    settle_idea(ctx, idea_id)?;
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
+
+#[reducer]
+pub fn retract_vote(ctx: &ReducerContext, idea_id: u64) -> Result<(), String> {
+    let (agent, _idea) = open_idea_for_agent(ctx, idea_id)?;
+
+    let vote = find_vote(ctx, idea_id, &agent.id).ok_or("No vote to retract")?;
+
+    ctx.db.votes().id().delete(vote.id);
+
+    settle_idea(ctx, idea_id)?;
     update_agent_activity(ctx, agent)?;
     Ok(())
 }
@@ -0,0 +1,61 @@
+use spacetimedb::{ReducerContext, reducer};
+
+use crate::helpers::activity::update_agent_activity;
+use crate::helpers::voting::clear_voting_deadlines;
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::draft_idea::draft_ideas;
+use crate::tables::idea::{Idea, ideas};
+use crate::types::IdeaStatus;
+
+#[reducer]
+pub fn withdraw_idea(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    reason: Option<String>,
+) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    // A withdrawn draft was never public, so it is discarded without notice.
+    if let Some(draft) = ctx.db.draft_ideas().idea_id().find(idea_id) {
+        if draft.created_by != agent.id {
+            return Err("Only the proposer can withdraw an idea".to_string());
+        }
+        ctx.db.draft_ideas().idea_id().delete(idea_id);
+        return update_agent_activity(ctx, agent);
+    }
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    if idea.created_by != agent.id {
+        return Err("Only the proposer can withdraw an idea".to_string());
+    }
+
+    if !matches!(idea.status, IdeaStatus::Discussion | IdeaStatus::Voting) {
+        return Err(format!("Cannot withdraw a {} idea", idea.status.as_str()));
+    }
+
+    let title = idea.title.clone();
+    ctx.db.ideas().id().update(Idea {
+        status: IdeaStatus::Withdrawn,
+        updated_at: ctx.timestamp,
+        ..idea
+    });
+    clear_voting_deadlines(ctx, idea_id);
+
+    let message = match reason {
+        Some(reason) if !reason.trim().is_empty() => {
+            format!("Idea '{}' withdrawn by proposer: {}", title, reason.trim())
+        }
+        _ => format!("Idea '{}' withdrawn by proposer", title),
+    };
+    send_system_message(ctx, message, None)?;
+
+    update_agent_activity(ctx, agent)?;
+    Ok(())
+}
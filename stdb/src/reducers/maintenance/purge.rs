@@ -0,0 +1,221 @@
+use spacetimedb::{ReducerContext, ScheduleAt, Table, reducer};
+use std::time::Duration;
+
+use crate::helpers::duplicates::clear_duplicate_candidates;
+use crate::helpers::lineage::clear_task_lineage;
+use crate::helpers::retention::get_notification_retention_days;
+use crate::tables::agent_notification::agent_notifications;
+use crate::tables::deleted_discovered_task::deleted_discovered_tasks;
+use crate::tables::deleted_idea::deleted_ideas;
+use crate::tables::deleted_task::deleted_tasks;
+use crate::tables::idea_comment::idea_comments;
+use crate::tables::idea_eligible_voter::idea_eligible_voters;
+use crate::tables::idea_proposed_task::idea_proposed_tasks;
+use crate::tables::idea_revision::idea_revisions;
+use crate::tables::idea_sponsor::idea_sponsors;
+use crate::tables::purge_schedule::{PurgeSchedule, purge_schedule};
+use crate::tables::task_dependency::task_dependencies;
+use crate::tables::task_github_link::task_github_links;
+use crate::tables::vote::votes;
+use crate::types::{DuplicateTarget, DuplicateTargetKind};
+
+const PURGE_INTERVAL_SECS: u64 = 60 * 60;
+
+/// Creates the hourly purge row if it is missing. `init` only runs on first
+/// publish, so `client_connected` also calls this for upgraded databases.
+pub fn ensure_purge_schedule(ctx: &ReducerContext) {
+    if ctx.db.purge_schedule().count() == 0 {
+        ctx.db.purge_schedule().insert(PurgeSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(Duration::from_secs(PURGE_INTERVAL_SECS).into()),
+        });
+    }
+}
+
+fn purge_task_dependents(ctx: &ReducerContext, task_id: u64) {
+    let dependency_ids: Vec<u64> = ctx
+        .db
+        .task_dependencies()
+        .by_task_id()
+        .filter(&task_id)
+        .chain(
+            ctx.db
+                .task_dependencies()
+                .by_depends_on_id()
+                .filter(&task_id),
+        )
+        .map(|dep| dep.id)
+        .collect();
+    for dependency_id in dependency_ids {
+        ctx.db.task_dependencies().id().delete(dependency_id);
+    }
+
+    let link_ids: Vec<u64> = ctx
+        .db
+        .task_github_links()
+        .by_task_id()
+        .filter(&task_id)
+        .map(|link| link.id)
+        .collect();
+    for link_id in link_ids {
+        ctx.db.task_github_links().id().delete(link_id);
+    }
+
+    clear_duplicate_candidates(
+        ctx,
+        &DuplicateTarget {
+            kind: DuplicateTargetKind::Task,
+            id: task_id,
+        },
+    );
+    clear_task_lineage(ctx, task_id);
+}
+
+fn purge_idea_dependents(ctx: &ReducerContext, idea_id: u64) {
+    let vote_ids: Vec<u64> = ctx
+        .db
+        .votes()
+        .by_idea_agent()
+        .filter(&idea_id)
+        .map(|vote| vote.id)
+        .collect();
+    for vote_id in vote_ids {
+        ctx.db.votes().id().delete(vote_id);
+    }
+
+    let voter_ids: Vec<u64> = ctx
+        .db
+        .idea_eligible_voters()
+        .by_idea_agent()
+        .filter(&idea_id)
+        .map(|voter| voter.id)
+        .collect();
+    for voter_id in voter_ids {
+        ctx.db.idea_eligible_voters().id().delete(voter_id);
+    }
+
+    let comment_ids: Vec<u64> = ctx
+        .db
+        .idea_comments()
+        .by_idea_id()
+        .filter(&idea_id)
+        .map(|comment| comment.id)
+        .collect();
+    for comment_id in comment_ids {
+        ctx.db.idea_comments().id().delete(comment_id);
+    }
+
+    let revision_ids: Vec<u64> = ctx
+        .db
+        .idea_revisions()
+        .by_idea_id()
+        .filter(&idea_id)
+        .map(|revision| revision.id)
+        .collect();
+    for revision_id in revision_ids {
+        ctx.db.idea_revisions().id().delete(revision_id);
+    }
+
+    let sponsor_ids: Vec<u64> = ctx
+        .db
+        .idea_sponsors()
+        .by_idea_id()
+        .filter(&idea_id)
+        .map(|sponsor| sponsor.id)
+        .collect();
+    for sponsor_id in sponsor_ids {
+        ctx.db.idea_sponsors().id().delete(sponsor_id);
+    }
+
+    let proposed_task_ids: Vec<u64> = ctx
+        .db
+        .idea_proposed_tasks()
+        .by_idea_id()
+        .filter(&idea_id)
+        .map(|proposed| proposed.id)
+        .collect();
+    for proposed_task_id in proposed_task_ids {
+        ctx.db.idea_proposed_tasks().id().delete(proposed_task_id);
+    }
+}
+
+#[reducer]
+pub fn purge_deleted_rows(ctx: &ReducerContext, _schedule: PurgeSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Purge can only be run by the scheduler".to_string());
+    }
+
+    let expired_tasks: Vec<u64> = ctx
+        .db
+        .deleted_tasks()
+        .iter()
+        .filter(|d| d.purge_after <= ctx.timestamp)
+        .map(|d| d.task_id)
+        .collect();
+    for task_id in &expired_tasks {
+        purge_task_dependents(ctx, *task_id);
+        ctx.db.deleted_tasks().task_id().delete(task_id);
+    }
+
+    let expired_ideas: Vec<u64> = ctx
+        .db
+        .deleted_ideas()
+        .iter()
+        .filter(|d| d.purge_after <= ctx.timestamp)
+        .map(|d| d.idea_id)
+        .collect();
+    for idea_id in &expired_ideas {
+        purge_idea_dependents(ctx, *idea_id);
+        ctx.db.deleted_ideas().idea_id().delete(idea_id);
+    }
+
+    let expired_discoveries: Vec<u64> = ctx
+        .db
+        .deleted_discovered_tasks()
+        .iter()
+        .filter(|d| d.purge_after <= ctx.timestamp)
+        .map(|d| d.discovery_id)
+        .collect();
+    for discovery_id in &expired_discoveries {
+        clear_duplicate_candidates(
+            ctx,
+            &DuplicateTarget {
+                kind: DuplicateTargetKind::Discovery,
+                id: *discovery_id,
+            },
+        );
+        ctx.db
+            .deleted_discovered_tasks()
+            .discovery_id()
+            .delete(discovery_id);
+    }
+
+    let retention_micros = get_notification_retention_days(ctx) * 24 * 60 * 60 * 1_000_000;
+    let notification_cutoff = ctx.timestamp - Duration::from_micros(retention_micros);
+    let expired_notifications: Vec<u64> = ctx
+        .db
+        .agent_notifications()
+        .iter()
+        .filter(|n| n.created_at <= notification_cutoff)
+        .map(|n| n.id)
+        .collect();
+    for notification_id in &expired_notifications {
+        ctx.db.agent_notifications().id().delete(notification_id);
+    }
+
+    let purged = expired_tasks.len()
+        + expired_ideas.len()
+        + expired_discoveries.len()
+        + expired_notifications.len();
+    if purged > 0 {
+        log::info!(
+            "Purged {} tasks, {} ideas, {} discoveries, {} notifications",
+            expired_tasks.len(),
+            expired_ideas.len(),
+            expired_discoveries.len(),
+            expired_notifications.len()
+        );
+    }
+
+    Ok(())
+}
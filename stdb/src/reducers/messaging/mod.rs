@@ -1,2 +1,3 @@
+pub mod notify;
 pub mod send;
 pub mod send_project;
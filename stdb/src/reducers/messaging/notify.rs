@@ -0,0 +1,51 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::tables::agent::agents;
+use crate::tables::agent_notification::{AgentNotification, agent_notifications};
+
+/// Leaves a direct notification for one agent.
+pub fn notify_agent(
+    ctx: &ReducerContext,
+    agent_id: &str,
+    content: String,
+    context_id: Option<String>,
+) {
+    ctx.db.agent_notifications().insert(AgentNotification {
+        id: 0,
+        agent_id: agent_id.to_string(),
+        content,
+        context_id,
+        created_at: ctx.timestamp,
+        read_at: None,
+    });
+}
+
+#[reducer]
+pub fn mark_notification_read(ctx: &ReducerContext, notification_id: u64) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let notification = ctx
+        .db
+        .agent_notifications()
+        .id()
+        .find(notification_id)
+        .ok_or("Notification not found")?;
+
+    if notification.agent_id != agent.id {
+        return Err("Notification belongs to another agent".to_string());
+    }
+
+    if notification.read_at.is_none() {
+        ctx.db.agent_notifications().id().update(AgentNotification {
+            read_at: Some(ctx.timestamp),
+            ..notification
+        });
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use spacetimedb::{ReducerContext, Table, reducer};
 
 use crate::helpers::activity::update_agent_activity;
-use crate::helpers::auth::require_role;
+use crate::helpers::auth::{require_not_suspended, require_role};
 use crate::tables::agent::agents;
 use crate::tables::channel::channels;
 use crate::tables::message::{Message, messages};
@@ -38,6 +38,8 @@ pub fn send_message(
         .find(ctx.sender())
         .ok_or("Agent not registered")?;
 
+    require_not_suspended(&agent)?;
+
     ctx.db.messages().insert(Message {
         id: 0,
         channel_id,
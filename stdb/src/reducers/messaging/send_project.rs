@@ -1,7 +1,8 @@
 use spacetimedb::{ReducerContext, Table, reducer};
 
 use crate::helpers::activity::update_agent_activity;
-use crate::helpers::auth::require_role;
+use crate::helpers::auth::{require_not_suspended, require_role};
+use crate::reducers::messaging::send::send_system_message;
 use crate::tables::agent::agents;
 use crate::tables::project::projects;
 use crate::tables::project_channel::project_channels;
@@ -49,6 +50,8 @@ pub fn send_project_message(
         .find(ctx.sender())
         .ok_or("Agent not registered")?;
 
+    require_not_suspended(&agent)?;
+
     ctx.db.project_messages().insert(ProjectMessage {
         id: 0,
         project_id,
@@ -62,3 +65,34 @@ pub fn send_project_message(
     update_agent_activity(ctx, agent)?;
     Ok(())
 }
+
+/// Posts a system message to a project's channel, or to `general` when the
+/// project has no channel.
+pub fn send_project_system_message(
+    ctx: &ReducerContext,
+    project_id: u64,
+    content: String,
+    context_id: Option<String>,
+) -> Result<(), String> {
+    if ctx
+        .db
+        .project_channels()
+        .project_id()
+        .find(project_id)
+        .is_none()
+    {
+        return send_system_message(ctx, content, None);
+    }
+
+    ctx.db.project_messages().insert(ProjectMessage {
+        id: 0,
+        project_id,
+        sender_id: "system".to_string(),
+        content,
+        message_type: MessageType::System,
+        context_id,
+        created_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
 pub mod agent;
 pub mod dev;
 pub mod discovered;
+pub mod github;
+pub mod governance;
 pub mod ideas;
+pub mod maintenance;
 pub mod messaging;
 pub mod projects;
 pub mod tasks;
+pub mod templates;
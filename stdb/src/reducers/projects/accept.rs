@@ -0,0 +1,95 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::reducers::messaging::send::send_system_message;
+use crate::reducers::projects::create::create_project_for_idea;
+use crate::tables::agent::agents;
+use crate::tables::idea::ideas;
+use crate::tables::idea_proposed_task::idea_proposed_tasks;
+use crate::tables::task::{Task, tasks};
+use crate::types::{AgentRole, TaskStatus};
+
+/// Turns an approved idea into a project in one step: the project, its
+/// channel, and one open task per proposed task, each linked back to the idea.
+/// `name` and `github_repo` default to the idea's title and proposed repo.
+#[reducer]
+pub fn accept_idea_into_project(
+    ctx: &ReducerContext,
+    idea_id: u64,
+    name: Option<String>,
+    github_repo: Option<String>,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let idea = ctx.db.ideas().id().find(idea_id).ok_or("Idea not found")?;
+
+    let github_repo = github_repo
+        .or(idea.proposed_repo.clone())
+        .ok_or("Idea has no proposed repo; pass github_repo")?;
+    let name = name
+        .filter(|n| !n.trim().is_empty())
+        .unwrap_or_else(|| idea.title.clone());
+
+    let project = create_project_for_idea(
+        ctx,
+        idea_id,
+        name,
+        github_repo,
+        idea.description.clone(),
+        sender.id.clone(),
+    )?;
+
+    let mut proposed: Vec<_> = ctx
+        .db
+        .idea_proposed_tasks()
+        .by_idea_id()
+        .filter(&idea_id)
+        .collect();
+    proposed.sort_by_key(|p| p.id);
+
+    let task_count = proposed.len();
+    for item in proposed {
+        ctx.db.tasks().insert(Task {
+            id: 0,
+            project_id: project.id,
+            title: item.title,
+            description: item.description,
+            status: TaskStatus::Open,
+            assigned_to: None,
+            claimed_at: None,
+            github_issue_url: None,
+            github_pr_url: None,
+            priority: item.priority,
+            source_idea_id: Some(idea_id),
+            review_count: 0,
+            blocked_from_status: None,
+            archived_reason: None,
+            status_changed_by: None,
+            status_changed_at: None,
+            created_at: ctx.timestamp,
+            updated_at: ctx.timestamp,
+            created_by: sender.id.clone(),
+            labels: Some(item.labels),
+        });
+    }
+
+    if task_count > 0 {
+        send_system_message(
+            ctx,
+            format!(
+                "{} proposed tasks from idea {} added to project '{}'",
+                task_count, idea_id, project.name
+            ),
+            None,
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,29 +1,25 @@
 use spacetimedb::{ReducerContext, Table, reducer};
 
 use crate::helpers::auth::require_role;
+use crate::helpers::github::parse_github_repo;
 use crate::reducers::messaging::send::send_system_message;
 use crate::tables::agent::agents;
 use crate::tables::idea::ideas;
 use crate::tables::project::{Project, projects};
 use crate::tables::project_channel::{ProjectChannel, project_channels};
-use crate::types::{AgentRole, IdeaStatus, ProjectStatus};
+use crate::types::{AgentRole, IdeaKind, IdeaStatus, ProjectStatus};
 
-#[reducer]
-pub fn create_project(
+/// Creates the project and its channel for an approved idea. Callers check
+/// the caller's role.
+pub fn create_project_for_idea(
     ctx: &ReducerContext,
     source_idea_id: u64,
     name: String,
     github_repo: String,
     description: String,
-) -> Result<(), String> {
-    require_role(ctx, AgentRole::Admin)?;
-
-    let sender = ctx
-        .db
-        .agents()
-        .identity()
-        .find(ctx.sender())
-        .ok_or("Agent not found")?;
+    created_by: String,
+) -> Result<Project, String> {
+    parse_github_repo(&github_repo)?;
 
     let idea = ctx
         .db
@@ -32,6 +28,10 @@ pub fn create_project(
         .find(source_idea_id)
         .ok_or("Idea not found")?;
 
+    if idea.kind == IdeaKind::Governance {
+        return Err("Governance proposals do not become projects".to_string());
+    }
+
     if idea.status != IdeaStatus::ApprovedForProject {
         return Err("Idea is not approved for project".to_string());
     }
@@ -55,7 +55,7 @@ pub fn create_project(
         description,
         status: ProjectStatus::Active,
         created_at: ctx.timestamp,
-        created_by: sender.id.clone(),
+        created_by,
     });
 
     ctx.db.project_channels().insert(ProjectChannel {
@@ -69,5 +69,34 @@ pub fn create_project(
         None,
     )?;
 
+    Ok(inserted_project)
+}
+
+#[reducer]
+pub fn create_project(
+    ctx: &ReducerContext,
+    source_idea_id: u64,
+    name: String,
+    github_repo: String,
+    description: String,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    create_project_for_idea(
+        ctx,
+        source_idea_id,
+        name,
+        github_repo,
+        description,
+        sender.id,
+    )?;
+
     Ok(())
 }
@@ -1,2 +1,3 @@
+pub mod accept;
 pub mod create;
 pub mod update_status;
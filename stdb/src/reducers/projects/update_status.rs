@@ -1,14 +1,105 @@
 use spacetimedb::{ReducerContext, reducer};
 
 use crate::helpers::auth::require_role;
+use crate::reducers::ideas::implement::implement_idea;
+use crate::reducers::messaging::send::send_system_message;
+use crate::reducers::tasks::update_status::apply_task_status;
+use crate::tables::agent::{Agent, agents};
+use crate::tables::idea::ideas;
 use crate::tables::project::{Project, projects};
-use crate::types::{AgentRole, ProjectStatus};
+use crate::tables::task::{Task, tasks};
+use crate::types::{AgentRole, AgentStatus, IdeaStatus, ProjectStatus, TaskStatus};
+
+fn is_valid_project_transition(current: &ProjectStatus, next: &ProjectStatus) -> bool {
+    match current {
+        ProjectStatus::Active => matches!(
+            next,
+            ProjectStatus::Paused | ProjectStatus::Completed | ProjectStatus::Archived
+        ),
+        ProjectStatus::Paused => matches!(next, ProjectStatus::Active | ProjectStatus::Archived),
+        ProjectStatus::Completed => matches!(next, ProjectStatus::Archived),
+        ProjectStatus::Archived => false,
+    }
+}
+
+fn release_claim(ctx: &ReducerContext, task: Task) {
+    let task_id = task.id;
+    let assignee_id = task.assigned_to.clone();
+
+    ctx.db.tasks().id().update(Task {
+        status: TaskStatus::Open,
+        assigned_to: None,
+        claimed_at: None,
+        status_changed_by: Some(ctx.sender()),
+        status_changed_at: Some(ctx.timestamp),
+        updated_at: ctx.timestamp,
+        ..task
+    });
+
+    if let Some(assignee) = assignee_id.and_then(|id| ctx.db.agents().id().find(&id)) {
+        if assignee.current_task_id == Some(task_id) {
+            ctx.db.agents().id().update(Agent {
+                status: AgentStatus::Online,
+                current_task_id: None,
+                ..assignee
+            });
+        }
+    }
+}
+
+/// Pausing releases claimed tasks back to `Open` and freezes in-progress work
+/// as `Blocked`; tasks in review are left for the reviewers.
+fn pause_project_tasks(ctx: &ReducerContext, project_tasks: Vec<Task>) -> Result<(), String> {
+    for task in project_tasks {
+        match task.status {
+            TaskStatus::Claimed => release_claim(ctx, task),
+            TaskStatus::InProgress => {
+                apply_task_status(ctx, task, TaskStatus::Blocked, true, None, None)?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn archive_open_tasks(
+    ctx: &ReducerContext,
+    project_tasks: Vec<Task>,
+    reason: &str,
+) -> Result<(), String> {
+    for task in project_tasks {
+        if !matches!(task.status, TaskStatus::Completed | TaskStatus::Archived) {
+            apply_task_status(
+                ctx,
+                task,
+                TaskStatus::Archived,
+                true,
+                None,
+                Some(reason.to_string()),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn implement_source_idea(ctx: &ReducerContext, project: &Project) -> Result<(), String> {
+    let Some(idea) = ctx.db.ideas().id().find(project.source_idea_id) else {
+        return Ok(());
+    };
+
+    if idea.status == IdeaStatus::Implemented {
+        return Ok(());
+    }
+
+    implement_idea(ctx, idea)
+}
 
 #[reducer]
 pub fn update_project_status(
     ctx: &ReducerContext,
     project_id: u64,
     status: ProjectStatus,
+    reason: Option<String>,
 ) -> Result<(), String> {
     require_role(ctx, AgentRole::Admin)?;
 
@@ -19,7 +110,63 @@ pub fn update_project_status(
         .find(project_id)
         .ok_or("Project not found")?;
 
-    ctx.db.projects().id().update(Project { status, ..project });
+    if project.status == status {
+        return Ok(());
+    }
+
+    if !is_valid_project_transition(&project.status, &status) {
+        return Err(format!(
+            "Invalid transition: {} -> {}",
+            project.status.as_str(),
+            status.as_str()
+        ));
+    }
+
+    let project_tasks: Vec<Task> = ctx.db.tasks().by_project_id().filter(&project_id).collect();
+
+    match status {
+        ProjectStatus::Active => {}
+        ProjectStatus::Paused => pause_project_tasks(ctx, project_tasks)?,
+        ProjectStatus::Completed => {
+            if project_tasks.iter().any(|t| {
+                matches!(
+                    t.status,
+                    TaskStatus::Claimed
+                        | TaskStatus::InProgress
+                        | TaskStatus::Review
+                        | TaskStatus::Blocked
+                )
+            }) {
+                return Err("Project has tasks in flight".to_string());
+            }
+            let reason = reason.as_deref().unwrap_or("Project completed");
+            archive_open_tasks(ctx, project_tasks, reason)?;
+            implement_source_idea(ctx, &project)?;
+        }
+        ProjectStatus::Archived => {
+            let reason = reason.as_deref().unwrap_or("Project archived");
+            archive_open_tasks(ctx, project_tasks, reason)?;
+            implement_source_idea(ctx, &project)?;
+        }
+    }
+
+    let previous = project.status.clone();
+    let name = project.name.clone();
+    ctx.db.projects().id().update(Project {
+        status: status.clone(),
+        ..project
+    });
+
+    send_system_message(
+        ctx,
+        format!(
+            "Project '{}' moved from {} to {}",
+            name,
+            previous.as_str(),
+            status.as_str()
+        ),
+        None,
+    )?;
 
     Ok(())
 }
@@ -41,6 +41,10 @@ pub fn add_task_dependency(
         return Err("Task cannot depend on itself".to_string());
     }
 
+    if dependency_type == DependencyType::DiscoveredFrom {
+        return Err("Discovered-from links are recorded when discoveries are approved".to_string());
+    }
+
     if ctx.db.tasks().id().find(task_id).is_none() {
         return Err("Task not found".to_string());
     }
@@ -1,11 +1,15 @@
 use spacetimedb::{ReducerContext, reducer};
 
+use crate::helpers::auth::require_not_suspended;
 use crate::tables::agent::{Agent, agents};
+use crate::tables::deleted_task::deleted_tasks;
 use crate::tables::project::projects;
 use crate::tables::task::{Task, tasks};
 use crate::tables::task_dependency::task_dependencies;
 use crate::types::{AgentStatus, DependencyType, ProjectStatus, TaskStatus};
 
+/// A soft-deleted blocker still blocks: it may be restored, and its
+/// dependency rows are only dropped when it is purged.
 fn has_open_blockers(ctx: &ReducerContext, task_id: u64) -> bool {
     for dep in ctx.db.task_dependencies().by_task_id().filter(&task_id) {
         if matches!(
@@ -16,6 +20,14 @@ fn has_open_blockers(ctx: &ReducerContext, task_id: u64) -> bool {
                 if blocker.status != TaskStatus::Completed {
                     return true;
                 }
+            } else if ctx
+                .db
+                .deleted_tasks()
+                .task_id()
+                .find(dep.depends_on_id)
+                .is_some_and(|deleted| deleted.task.status != TaskStatus::Completed)
+            {
+                return true;
             }
         }
     }
@@ -31,6 +43,8 @@ pub fn claim_task(ctx: &ReducerContext, task_id: u64) -> Result<(), String> {
         .find(ctx.sender())
         .ok_or("Agent not found")?;
 
+    require_not_suspended(&agent)?;
+
     let task = ctx.db.tasks().id().find(task_id).ok_or("Task not found")?;
     let project = ctx
         .db
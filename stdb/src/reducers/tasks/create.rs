@@ -1,11 +1,12 @@
 use spacetimedb::{ReducerContext, Table, reducer};
 
 use crate::helpers::auth::require_role;
+use crate::helpers::github::{link_task_github_ref, parse_project_github_url};
 use crate::reducers::messaging::send::send_system_message;
 use crate::tables::agent::agents;
 use crate::tables::project::projects;
 use crate::tables::task::{Task, tasks};
-use crate::types::{AgentRole, TaskStatus};
+use crate::types::{AgentRole, GithubRefKind, ProjectStatus, TaskStatus};
 
 #[reducer]
 pub fn create_task(
@@ -26,10 +27,25 @@ pub fn create_task(
         .find(ctx.sender())
         .ok_or("Agent not found")?;
 
-    if ctx.db.projects().id().find(project_id).is_none() {
-        return Err("Project not found".to_string());
+    let project = ctx
+        .db
+        .projects()
+        .id()
+        .find(project_id)
+        .ok_or("Project not found")?;
+
+    if matches!(
+        project.status,
+        ProjectStatus::Completed | ProjectStatus::Archived
+    ) {
+        return Err(format!("Project is {}", project.status.as_str()));
     }
 
+    let issue_ref = github_issue_url
+        .as_deref()
+        .map(|url| parse_project_github_url(&project, url, GithubRefKind::Issue))
+        .transpose()?;
+
     let inserted = ctx.db.tasks().insert(Task {
         id: 0,
         project_id,
@@ -38,7 +54,7 @@ pub fn create_task(
         status: TaskStatus::Open,
         assigned_to: None,
         claimed_at: None,
-        github_issue_url,
+        github_issue_url: issue_ref.as_ref().map(|r| r.url()),
         github_pr_url: None,
         priority,
         source_idea_id,
@@ -50,8 +66,13 @@ pub fn create_task(
         created_at: ctx.timestamp,
         updated_at: ctx.timestamp,
         created_by: sender.id,
+        labels: None,
     });
 
+    if let Some(issue_ref) = &issue_ref {
+        link_task_github_ref(ctx, &inserted, issue_ref);
+    }
+
     send_system_message(
         ctx,
         format!("New task created: {}", inserted.id),
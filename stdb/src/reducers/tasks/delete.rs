@@ -0,0 +1,84 @@
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::retention::{purge_after, require_restorable};
+use crate::tables::agent::{Agent, agents};
+use crate::tables::deleted_task::{DeletedTask, deleted_tasks};
+use crate::tables::project::projects;
+use crate::tables::task::tasks;
+use crate::types::{AgentRole, AgentStatus};
+
+#[reducer]
+pub fn soft_delete_task(
+    ctx: &ReducerContext,
+    task_id: u64,
+    reason: Option<String>,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let task = ctx.db.tasks().id().find(task_id).ok_or("Task not found")?;
+
+    if let Some(assignee) = task
+        .assigned_to
+        .as_ref()
+        .and_then(|id| ctx.db.agents().id().find(id))
+    {
+        if assignee.current_task_id == Some(task_id) {
+            ctx.db.agents().id().update(Agent {
+                status: AgentStatus::Online,
+                current_task_id: None,
+                ..assignee
+            });
+        }
+    }
+
+    ctx.db.tasks().id().delete(task_id);
+    ctx.db.deleted_tasks().insert(DeletedTask {
+        task_id,
+        task,
+        reason,
+        deleted_by: sender.id,
+        deleted_at: ctx.timestamp,
+        purge_after: purge_after(ctx),
+    });
+
+    log::info!("Task {} soft-deleted", task_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn restore_task(ctx: &ReducerContext, task_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let deleted = ctx
+        .db
+        .deleted_tasks()
+        .task_id()
+        .find(task_id)
+        .ok_or("Deleted task not found")?;
+
+    require_restorable(ctx, deleted.purge_after)?;
+
+    if ctx
+        .db
+        .projects()
+        .id()
+        .find(deleted.task.project_id)
+        .is_none()
+    {
+        return Err("Project not found".to_string());
+    }
+
+    ctx.db.deleted_tasks().task_id().delete(task_id);
+    ctx.db.tasks().insert(deleted.task);
+
+    log::info!("Task {} restored", task_id);
+    Ok(())
+}
@@ -1,4 +1,5 @@
 pub mod add_dependency;
 pub mod claim;
 pub mod create;
+pub mod delete;
 pub mod update_status;
@@ -1,8 +1,10 @@
 use spacetimedb::{ReducerContext, reducer};
 
+use crate::helpers::github::{link_task_github_ref, parse_project_github_url};
 use crate::tables::agent::{Agent, agents};
+use crate::tables::project::projects;
 use crate::tables::task::{Task, tasks};
-use crate::types::{AgentRole, AgentStatus, TaskStatus};
+use crate::types::{AgentRole, AgentStatus, GithubRefKind, TaskStatus};
 
 fn is_admin_or_zoe(role: &AgentRole) -> bool {
     matches!(role, AgentRole::Admin | AgentRole::Zoe)
@@ -28,27 +30,18 @@ fn is_valid_task_transition(
     }
 }
 
-#[reducer]
-pub fn update_task_status(
+/// Validates and applies a status change on behalf of `ctx.sender()`. Shared by
+/// `update_task_status` and trusted integrations; `privileged` grants the
+/// admin/zoe-only transitions.
+pub fn apply_task_status(
     ctx: &ReducerContext,
-    task_id: u64,
+    task: Task,
     status: TaskStatus,
+    privileged: bool,
     github_pr_url: Option<String>,
     archive_reason: Option<String>,
-) -> Result<(), String> {
-    let agent = ctx
-        .db
-        .agents()
-        .identity()
-        .find(ctx.sender())
-        .ok_or("Agent not found")?;
-
-    let task = ctx.db.tasks().id().find(task_id).ok_or("Task not found")?;
-    let privileged = is_admin_or_zoe(&agent.role);
-
-    if task.assigned_to != Some(agent.id.clone()) && !privileged {
-        return Err("Not assigned to this task".to_string());
-    }
+) -> Result<Task, String> {
+    let task_id = task.id;
 
     if status == TaskStatus::Archived && !privileged {
         return Err("Only admin/zoe can archive tasks".to_string());
@@ -80,6 +73,23 @@ pub fn update_task_status(
         ));
     }
 
+    let pr_ref = match github_pr_url.as_deref() {
+        Some(url) => {
+            let project = ctx
+                .db
+                .projects()
+                .id()
+                .find(task.project_id)
+                .ok_or("Project not found")?;
+            Some(parse_project_github_url(
+                &project,
+                url,
+                GithubRefKind::PullRequest,
+            )?)
+        }
+        None => None,
+    };
+
     let mut blocked_from_status = task.blocked_from_status.clone();
     let mut archived_reason_value = task.archived_reason.clone();
     let status_changed = task.status != status;
@@ -108,9 +118,9 @@ pub fn update_task_status(
         archived_reason_value = None;
     }
 
-    ctx.db.tasks().id().update(Task {
+    let updated = ctx.db.tasks().id().update(Task {
         status: status.clone(),
-        github_pr_url: github_pr_url.or(task.github_pr_url),
+        github_pr_url: pr_ref.as_ref().map(|r| r.url()).or(task.github_pr_url),
         blocked_from_status,
         archived_reason: archived_reason_value,
         status_changed_by,
@@ -119,6 +129,10 @@ pub fn update_task_status(
         ..task
     });
 
+    if let Some(pr_ref) = &pr_ref {
+        link_task_github_ref(ctx, &updated, pr_ref);
+    }
+
     if let Some(assignee_id) = ctx
         .db
         .tasks()
@@ -145,6 +159,33 @@ pub fn update_task_status(
         }
     }
 
+    Ok(updated)
+}
+
+#[reducer]
+pub fn update_task_status(
+    ctx: &ReducerContext,
+    task_id: u64,
+    status: TaskStatus,
+    github_pr_url: Option<String>,
+    archive_reason: Option<String>,
+) -> Result<(), String> {
+    let agent = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let task = ctx.db.tasks().id().find(task_id).ok_or("Task not found")?;
+    let privileged = is_admin_or_zoe(&agent.role);
+
+    if task.assigned_to != Some(agent.id.clone()) && !privileged {
+        return Err("Not assigned to this task".to_string());
+    }
+
+    apply_task_status(ctx, task, status, privileged, github_pr_url, archive_reason)?;
+
     if let Some(current_agent) = ctx.db.agents().id().find(&agent.id) {
         ctx.db.agents().id().update(Agent {
             last_active_at: ctx.timestamp,
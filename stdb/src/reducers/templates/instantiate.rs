@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::reducers::messaging::send::send_system_message;
+use crate::tables::agent::agents;
+use crate::tables::project::projects;
+use crate::tables::task::{Task, tasks};
+use crate::tables::task_dependency::{TaskDependency, task_dependencies};
+use crate::tables::task_template::task_templates;
+use crate::tables::task_template_dependency::task_template_dependencies;
+use crate::tables::task_template_item::task_template_items;
+use crate::types::{AgentRole, ProjectStatus, TaskStatus};
+
+#[reducer]
+pub fn instantiate_template(
+    ctx: &ReducerContext,
+    template_id: u64,
+    project_id: u64,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let template = ctx
+        .db
+        .task_templates()
+        .id()
+        .find(template_id)
+        .ok_or("Template not found")?;
+
+    let project = ctx
+        .db
+        .projects()
+        .id()
+        .find(project_id)
+        .ok_or("Project not found")?;
+
+    if matches!(
+        project.status,
+        ProjectStatus::Completed | ProjectStatus::Archived
+    ) {
+        return Err(format!("Project is {}", project.status.as_str()));
+    }
+
+    let mut items: Vec<_> = ctx
+        .db
+        .task_template_items()
+        .by_template_id()
+        .filter(&template_id)
+        .collect();
+    if items.is_empty() {
+        return Err("Template has no tasks".to_string());
+    }
+    items.sort_by_key(|item| item.id);
+
+    let mut task_ids: HashMap<u64, u64> = HashMap::new();
+    for item in items {
+        let inserted = ctx.db.tasks().insert(Task {
+            id: 0,
+            project_id,
+            title: item.title,
+            description: item.description,
+            status: TaskStatus::Open,
+            assigned_to: None,
+            claimed_at: None,
+            github_issue_url: None,
+            github_pr_url: None,
+            priority: item.priority,
+            source_idea_id: Some(project.source_idea_id),
+            review_count: 0,
+            blocked_from_status: None,
+            archived_reason: None,
+            status_changed_by: None,
+            status_changed_at: None,
+            created_at: ctx.timestamp,
+            updated_at: ctx.timestamp,
+            created_by: sender.id.clone(),
+            labels: Some(item.labels),
+        });
+        task_ids.insert(item.id, inserted.id);
+    }
+
+    for dep in ctx
+        .db
+        .task_template_dependencies()
+        .by_template_id()
+        .filter(&template_id)
+    {
+        let (Some(task_id), Some(depends_on_id)) = (
+            task_ids.get(&dep.item_id),
+            task_ids.get(&dep.depends_on_item_id),
+        ) else {
+            return Err(format!("Template dependency {} is dangling", dep.id));
+        };
+
+        ctx.db.task_dependencies().insert(TaskDependency {
+            id: 0,
+            task_id: *task_id,
+            depends_on_id: *depends_on_id,
+            dependency_type: dep.dependency_type,
+            created_at: ctx.timestamp,
+        });
+    }
+
+    send_system_message(
+        ctx,
+        format!(
+            "Template '{}' instantiated in project '{}' ({} tasks)",
+            template.name,
+            project.name,
+            task_ids.len()
+        ),
+        Some("general"),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+use std::collections::HashSet;
+
+use spacetimedb::{ReducerContext, Table, reducer};
+
+use crate::helpers::auth::require_role;
+use crate::helpers::labels::normalize_labels;
+use crate::tables::agent::agents;
+use crate::tables::task_template::{TaskTemplate, task_templates};
+use crate::tables::task_template_dependency::{TaskTemplateDependency, task_template_dependencies};
+use crate::tables::task_template_item::{TaskTemplateItem, task_template_items};
+use crate::types::{AgentRole, DependencyType};
+
+fn touch_template(ctx: &ReducerContext, template_id: u64) -> Result<(), String> {
+    let template = ctx
+        .db
+        .task_templates()
+        .id()
+        .find(template_id)
+        .ok_or("Template not found")?;
+
+    ctx.db.task_templates().id().update(TaskTemplate {
+        updated_at: ctx.timestamp,
+        ..template
+    });
+    Ok(())
+}
+
+fn would_create_cycle(ctx: &ReducerContext, from: u64, to: u64) -> bool {
+    let mut visited = HashSet::new();
+    let mut queue = vec![to];
+
+    while let Some(current) = queue.pop() {
+        if current == from {
+            return true;
+        }
+
+        if visited.insert(current) {
+            for dep in ctx
+                .db
+                .task_template_dependencies()
+                .by_item_id()
+                .filter(&current)
+            {
+                queue.push(dep.depends_on_item_id);
+            }
+        }
+    }
+
+    false
+}
+
+#[reducer]
+pub fn create_task_template(
+    ctx: &ReducerContext,
+    name: String,
+    description: String,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let sender = ctx
+        .db
+        .agents()
+        .identity()
+        .find(ctx.sender())
+        .ok_or("Agent not found")?;
+
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Template name required".to_string());
+    }
+
+    if ctx.db.task_templates().name().find(&name).is_some() {
+        return Err("Template name already exists".to_string());
+    }
+
+    ctx.db.task_templates().insert(TaskTemplate {
+        id: 0,
+        name,
+        description,
+        created_by: sender.id,
+        created_at: ctx.timestamp,
+        updated_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn delete_task_template(ctx: &ReducerContext, template_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    if ctx.db.task_templates().id().find(template_id).is_none() {
+        return Err("Template not found".to_string());
+    }
+
+    let dependency_ids: Vec<u64> = ctx
+        .db
+        .task_template_dependencies()
+        .by_template_id()
+        .filter(&template_id)
+        .map(|dep| dep.id)
+        .collect();
+    for dependency_id in dependency_ids {
+        ctx.db
+            .task_template_dependencies()
+            .id()
+            .delete(dependency_id);
+    }
+
+    let item_ids: Vec<u64> = ctx
+        .db
+        .task_template_items()
+        .by_template_id()
+        .filter(&template_id)
+        .map(|item| item.id)
+        .collect();
+    for item_id in item_ids {
+        ctx.db.task_template_items().id().delete(item_id);
+    }
+
+    ctx.db.task_templates().id().delete(template_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn add_task_template_item(
+    ctx: &ReducerContext,
+    template_id: u64,
+    title: String,
+    description: String,
+    priority: u8,
+    labels: Vec<String>,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    if title.trim().is_empty() {
+        return Err("Title required".to_string());
+    }
+
+    touch_template(ctx, template_id)?;
+
+    ctx.db.task_template_items().insert(TaskTemplateItem {
+        id: 0,
+        template_id,
+        title,
+        description,
+        priority,
+        labels: normalize_labels(labels),
+        created_at: ctx.timestamp,
+    });
+
+    Ok(())
+}
+
+#[reducer]
+pub fn remove_task_template_item(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let item = ctx
+        .db
+        .task_template_items()
+        .id()
+        .find(item_id)
+        .ok_or("Template item not found")?;
+
+    let dependency_ids: Vec<u64> = ctx
+        .db
+        .task_template_dependencies()
+        .by_template_id()
+        .filter(&item.template_id)
+        .filter(|dep| dep.item_id == item_id || dep.depends_on_item_id == item_id)
+        .map(|dep| dep.id)
+        .collect();
+    for dependency_id in dependency_ids {
+        ctx.db
+            .task_template_dependencies()
+            .id()
+            .delete(dependency_id);
+    }
+
+    ctx.db.task_template_items().id().delete(item_id);
+    touch_template(ctx, item.template_id)
+}
+
+#[reducer]
+pub fn add_task_template_dependency(
+    ctx: &ReducerContext,
+    item_id: u64,
+    depends_on_item_id: u64,
+    dependency_type: DependencyType,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    if item_id == depends_on_item_id {
+        return Err("Template item cannot depend on itself".to_string());
+    }
+
+    if dependency_type == DependencyType::DiscoveredFrom {
+        return Err("Discovered-from links are recorded when discoveries are approved".to_string());
+    }
+
+    let item = ctx
+        .db
+        .task_template_items()
+        .id()
+        .find(item_id)
+        .ok_or("Template item not found")?;
+    let depends_on = ctx
+        .db
+        .task_template_items()
+        .id()
+        .find(depends_on_item_id)
+        .ok_or("Dependency template item not found")?;
+
+    if item.template_id != depends_on.template_id {
+        return Err("Template items belong to different templates".to_string());
+    }
+
+    if would_create_cycle(ctx, item_id, depends_on_item_id) {
+        return Err("Would create circular dependency".to_string());
+    }
+
+    let already_exists = ctx
+        .db
+        .task_template_dependencies()
+        .by_item_id()
+        .filter(&item_id)
+        .any(|dep| {
+            dep.depends_on_item_id == depends_on_item_id && dep.dependency_type == dependency_type
+        });
+
+    if already_exists {
+        return Err("Dependency already exists".to_string());
+    }
+
+    ctx.db
+        .task_template_dependencies()
+        .insert(TaskTemplateDependency {
+            id: 0,
+            template_id: item.template_id,
+            item_id,
+            depends_on_item_id,
+            dependency_type,
+            created_at: ctx.timestamp,
+        });
+
+    touch_template(ctx, item.template_id)
+}
+
+#[reducer]
+pub fn remove_task_template_dependency(
+    ctx: &ReducerContext,
+    dependency_id: u64,
+) -> Result<(), String> {
+    require_role(ctx, AgentRole::Admin)?;
+
+    let dependency = ctx
+        .db
+        .task_template_dependencies()
+        .id()
+        .find(dependency_id)
+        .ok_or("Template dependency not found")?;
+
+    ctx.db
+        .task_template_dependencies()
+        .id()
+        .delete(dependency_id);
+    touch_template(ctx, dependency.template_id)
+}
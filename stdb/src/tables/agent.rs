@@ -17,4 +17,8 @@ pub struct Agent {
     pub current_task_id: Option<u64>,
     pub created_at: Timestamp,
     pub last_active_at: Timestamp,
+    #[default(None::<Timestamp>)]
+    pub suspended_at: Option<Timestamp>,
+    #[default(None::<String>)]
+    pub suspension_reason: Option<String>,
 }
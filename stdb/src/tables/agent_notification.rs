@@ -0,0 +1,13 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(accessor = agent_notifications, index(accessor = by_agent_id, btree(columns = [agent_id])))]
+pub struct AgentNotification {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub agent_id: String,
+    pub content: String,
+    pub context_id: Option<String>,
+    pub created_at: Timestamp,
+    pub read_at: Option<Timestamp>,
+}
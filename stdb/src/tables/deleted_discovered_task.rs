@@ -0,0 +1,14 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::tables::discovered_task::DiscoveredTask;
+
+#[table(accessor = deleted_discovered_tasks, public)]
+pub struct DeletedDiscoveredTask {
+    #[primary_key]
+    pub discovery_id: u64,
+    pub discovery: DiscoveredTask,
+    pub reason: Option<String>,
+    pub deleted_by: String,
+    pub deleted_at: Timestamp,
+    pub purge_after: Timestamp,
+}
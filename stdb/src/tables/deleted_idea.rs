@@ -0,0 +1,14 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::tables::idea::Idea;
+
+#[table(accessor = deleted_ideas, public)]
+pub struct DeletedIdea {
+    #[primary_key]
+    pub idea_id: u64,
+    pub idea: Idea,
+    pub reason: Option<String>,
+    pub deleted_by: String,
+    pub deleted_at: Timestamp,
+    pub purge_after: Timestamp,
+}
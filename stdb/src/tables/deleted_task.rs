@@ -0,0 +1,14 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::tables::task::Task;
+
+#[table(accessor = deleted_tasks, public)]
+pub struct DeletedTask {
+    #[primary_key]
+    pub task_id: u64,
+    pub task: Task,
+    pub reason: Option<String>,
+    pub deleted_by: String,
+    pub deleted_at: Timestamp,
+    pub purge_after: Timestamp,
+}
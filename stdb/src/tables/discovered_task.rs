@@ -1,6 +1,6 @@
 use spacetimedb::{Timestamp, table};
 
-use crate::types::DiscoveredTaskStatus;
+use crate::types::{DiscoveredTaskStatus, DuplicateTarget};
 
 #[table(
     accessor = discovered_tasks,
@@ -27,4 +27,10 @@ pub struct DiscoveredTask {
     pub created_at: Timestamp,
     pub reviewed_at: Option<Timestamp>,
     pub reviewed_by: Option<String>,
+    #[default(None::<DuplicateTarget>)]
+    pub duplicate_of: Option<DuplicateTarget>,
+    #[default(false)]
+    pub blocks_current_task: bool,
+    #[default(None::<u64>)]
+    pub created_idea_id: Option<u64>,
 }
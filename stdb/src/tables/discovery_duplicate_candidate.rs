@@ -0,0 +1,20 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::DuplicateTargetKind;
+
+#[table(
+    accessor = discovery_duplicate_candidates,
+    public,
+    index(accessor = by_discovery_id, btree(columns = [discovery_id])),
+    index(accessor = by_candidate, btree(columns = [candidate_kind, candidate_id]))
+)]
+pub struct DiscoveryDuplicateCandidate {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub discovery_id: u64,
+    pub candidate_kind: DuplicateTargetKind,
+    pub candidate_id: u64,
+    pub similarity: f64,
+    pub created_at: Timestamp,
+}
@@ -0,0 +1,19 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::TriageAction;
+
+#[table(
+    accessor = discovery_triage_decisions,
+    public,
+    index(accessor = by_discovery_id, btree(columns = [discovery_id]))
+)]
+pub struct DiscoveryTriageDecision {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub discovery_id: u64,
+    pub rule_id: u64,
+    pub rule_name: String,
+    pub action: TriageAction,
+    pub created_at: Timestamp,
+}
@@ -0,0 +1,24 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::{TriageAction, TriageConditions};
+
+#[table(
+    accessor = discovery_triage_rules,
+    public,
+    index(accessor = by_project_id, btree(columns = [project_id]))
+)]
+pub struct DiscoveryTriageRule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub project_id: u64,
+    pub name: String,
+    pub position: u32,
+    pub conditions: TriageConditions,
+    pub action: TriageAction,
+    pub reason: Option<String>,
+    pub enabled: bool,
+    pub created_by: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
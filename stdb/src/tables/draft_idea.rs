@@ -0,0 +1,11 @@
+use spacetimedb::table;
+
+use crate::tables::idea::Idea;
+
+#[table(accessor = draft_ideas, index(accessor = by_created_by, btree(columns = [created_by])))]
+pub struct DraftIdea {
+    #[primary_key]
+    pub idea_id: u64,
+    pub created_by: String,
+    pub idea: Idea,
+}
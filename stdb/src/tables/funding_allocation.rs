@@ -0,0 +1,14 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(accessor = funding_allocations, public, index(accessor = by_round_agent, btree(columns = [round_id, agent_id])))]
+pub struct FundingAllocation {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub round_id: u64,
+    pub agent_id: String,
+    pub idea_id: u64,
+    pub points: u32,
+    pub cost: u64,
+    pub created_at: Timestamp,
+}
@@ -0,0 +1,22 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::{FundingResult, FundingRoundStatus};
+
+#[table(accessor = funding_rounds, public)]
+pub struct FundingRound {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub title: String,
+    pub idea_ids: Vec<u64>,
+    pub budget: u32,
+    pub quadratic: bool,
+    pub approve_count: u16,
+    pub eligible_agents: Vec<String>,
+    pub status: FundingRoundStatus,
+    pub results: Vec<FundingResult>,
+    pub created_by: String,
+    pub created_at: Timestamp,
+    pub closed_at: Option<Timestamp>,
+    pub closes_at: Timestamp,
+}
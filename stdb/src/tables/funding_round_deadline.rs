@@ -0,0 +1,16 @@
+use spacetimedb::{ScheduleAt, table};
+
+use crate::reducers::ideas::funding::finalize_funding_round;
+
+#[table(
+    accessor = funding_round_deadlines,
+    scheduled(finalize_funding_round),
+    index(accessor = by_round_id, btree(columns = [round_id]))
+)]
+pub struct FundingRoundDeadline {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub round_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
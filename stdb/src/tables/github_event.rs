@@ -0,0 +1,24 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::{GithubEventKind, GithubEventOutcome};
+
+#[table(
+    accessor = github_events,
+    public,
+    index(accessor = by_outcome, btree(columns = [outcome])),
+    index(accessor = by_received_at, btree(columns = [received_at]))
+)]
+pub struct GithubEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub kind: GithubEventKind,
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+    pub linked_issue_numbers: Vec<u64>,
+    pub outcome: GithubEventOutcome,
+    pub task_ids: Vec<u64>,
+    pub detail: Option<String>,
+    pub received_at: Timestamp,
+}
@@ -0,0 +1,16 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::{AppliedConfigChange, GovernanceActionKind};
+
+#[table(accessor = governance_actions, public, index(accessor = by_idea_id, btree(columns = [idea_id])))]
+pub struct GovernanceAction {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub idea_id: u64,
+    pub action: GovernanceActionKind,
+    pub changes: Vec<AppliedConfigChange>,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub created_at: Timestamp,
+}
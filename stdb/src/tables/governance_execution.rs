@@ -0,0 +1,16 @@
+use spacetimedb::{ScheduleAt, table};
+
+use crate::reducers::governance::proposals::execute_governance_change;
+
+#[table(
+    accessor = governance_executions,
+    scheduled(execute_governance_change),
+    index(accessor = by_idea_id, btree(columns = [idea_id]))
+)]
+pub struct GovernanceExecution {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub idea_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
@@ -1,6 +1,6 @@
 use spacetimedb::{Timestamp, table};
 
-use crate::types::IdeaStatus;
+use crate::types::{ConfigChange, IdeaKind, IdeaStatus};
 
 #[table(accessor = ideas, public, index(accessor = by_status, btree(columns = [status])))]
 pub struct Idea {
@@ -22,4 +22,40 @@ pub struct Idea {
     pub created_by: String,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    #[default(Timestamp::UNIX_EPOCH)]
+    pub voting_ends_at: Timestamp,
+    #[default(None::<String>)]
+    pub governance_profile: Option<String>,
+    #[default(false)]
+    pub weighted_voting: bool,
+    #[default(0)]
+    pub up_weight: u32,
+    #[default(0)]
+    pub down_weight: u32,
+    #[default(0)]
+    pub veto_weight: u32,
+    #[default(0)]
+    pub total_weight: u32,
+    #[default(0)]
+    pub delegated_weight: u32,
+    #[default(1)]
+    pub revision: u32,
+    #[default(None::<u64>)]
+    pub merged_into_id: Option<u64>,
+    #[default(None::<Vec<String>>)]
+    pub reviewers: Option<Vec<String>>,
+    #[default(None::<Timestamp>)]
+    pub discussion_started_at: Option<Timestamp>,
+    #[default(None::<Timestamp>)]
+    pub voting_started_at: Option<Timestamp>,
+    #[default(0)]
+    pub sponsor_count: u16,
+    #[default(None::<String>)]
+    pub proposed_repo: Option<String>,
+    #[default(IdeaKind::Project)]
+    pub kind: IdeaKind,
+    #[default(None::<Vec<ConfigChange>>)]
+    pub config_changes: Option<Vec<ConfigChange>>,
+    #[default(None::<u64>)]
+    pub funding_round_id: Option<u64>,
 }
@@ -0,0 +1,16 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::AgentRole;
+
+#[table(accessor = idea_categories, public)]
+pub struct IdeaCategory {
+    #[primary_key]
+    pub name: String,
+    pub description: String,
+    pub min_proposer_role: AgentRole,
+    pub quorum_override: Option<u16>,
+    pub default_reviewers: Vec<String>,
+    pub created_by: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
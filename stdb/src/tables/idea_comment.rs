@@ -0,0 +1,12 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(accessor = idea_comments, public, index(accessor = by_idea_id, btree(columns = [idea_id])))]
+pub struct IdeaComment {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub idea_id: u64,
+    pub agent_id: String,
+    pub content: String,
+    pub created_at: Timestamp,
+}
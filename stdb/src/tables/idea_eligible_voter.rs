@@ -0,0 +1,11 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(accessor = idea_eligible_voters, public, index(accessor = by_idea_agent, btree(columns = [idea_id, agent_id])))]
+pub struct IdeaEligibleVoter {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub idea_id: u64,
+    pub agent_id: String,
+    pub created_at: Timestamp,
+}
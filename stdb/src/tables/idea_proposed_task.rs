@@ -0,0 +1,18 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(
+    accessor = idea_proposed_tasks,
+    public,
+    index(accessor = by_idea_id, btree(columns = [idea_id]))
+)]
+pub struct IdeaProposedTask {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub idea_id: u64,
+    pub title: String,
+    pub description: String,
+    pub priority: u8,
+    pub labels: Vec<String>,
+    pub created_at: Timestamp,
+}
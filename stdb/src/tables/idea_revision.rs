@@ -0,0 +1,16 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(accessor = idea_revisions, public, index(accessor = by_idea_id, btree(columns = [idea_id])))]
+pub struct IdeaRevision {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub idea_id: u64,
+    pub revision: u32,
+    pub title: String,
+    pub description: String,
+    pub substantive: bool,
+    pub note: Option<String>,
+    pub amended_by: String,
+    pub created_at: Timestamp,
+}
@@ -0,0 +1,11 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(accessor = idea_sponsors, public, index(accessor = by_idea_id, btree(columns = [idea_id])))]
+pub struct IdeaSponsor {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub idea_id: u64,
+    pub agent_id: String,
+    pub created_at: Timestamp,
+}
@@ -0,0 +1,16 @@
+use spacetimedb::{ScheduleAt, table};
+
+use crate::reducers::ideas::finalize::finalize_idea_voting;
+
+#[table(
+    accessor = idea_voting_deadlines,
+    scheduled(finalize_idea_voting),
+    index(accessor = by_idea_id, btree(columns = [idea_id]))
+)]
+pub struct IdeaVotingDeadline {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub idea_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
@@ -1,27 +1,85 @@
 pub mod agent;
+pub mod agent_notification;
 pub mod channel;
 pub mod config;
+pub mod deleted_discovered_task;
+pub mod deleted_idea;
+pub mod deleted_task;
 pub mod discovered_task;
+pub mod discovery_duplicate_candidate;
+pub mod discovery_triage_decision;
+pub mod discovery_triage_rule;
+pub mod draft_idea;
+pub mod funding_allocation;
+pub mod funding_round;
+pub mod funding_round_deadline;
+pub mod github_event;
+pub mod governance_action;
+pub mod governance_execution;
 pub mod idea;
+pub mod idea_category;
+pub mod idea_comment;
+pub mod idea_eligible_voter;
+pub mod idea_proposed_task;
+pub mod idea_revision;
+pub mod idea_sponsor;
+pub mod idea_voting_deadline;
 pub mod identity_role;
 pub mod message;
 pub mod project;
 pub mod project_channel;
 pub mod project_message;
+pub mod purge_schedule;
 pub mod task;
 pub mod task_dependency;
+pub mod task_github_link;
+pub mod task_lineage;
+pub mod task_template;
+pub mod task_template_dependency;
+pub mod task_template_item;
+pub mod veto_override;
 pub mod vote;
+pub mod vote_delegation;
 
 pub use agent::Agent;
+pub use agent_notification::AgentNotification;
 pub use channel::Channel;
 pub use config::Config;
+pub use deleted_discovered_task::DeletedDiscoveredTask;
+pub use deleted_idea::DeletedIdea;
+pub use deleted_task::DeletedTask;
 pub use discovered_task::DiscoveredTask;
+pub use discovery_duplicate_candidate::DiscoveryDuplicateCandidate;
+pub use discovery_triage_decision::DiscoveryTriageDecision;
+pub use discovery_triage_rule::DiscoveryTriageRule;
+pub use draft_idea::DraftIdea;
+pub use funding_allocation::FundingAllocation;
+pub use funding_round::FundingRound;
+pub use funding_round_deadline::FundingRoundDeadline;
+pub use github_event::GithubEvent;
+pub use governance_action::GovernanceAction;
+pub use governance_execution::GovernanceExecution;
 pub use idea::Idea;
+pub use idea_category::IdeaCategory;
+pub use idea_comment::IdeaComment;
+pub use idea_eligible_voter::IdeaEligibleVoter;
+pub use idea_proposed_task::IdeaProposedTask;
+pub use idea_revision::IdeaRevision;
+pub use idea_sponsor::IdeaSponsor;
+pub use idea_voting_deadline::IdeaVotingDeadline;
 pub use identity_role::IdentityRole;
 pub use message::Message;
 pub use project::Project;
 pub use project_channel::ProjectChannel;
 pub use project_message::ProjectMessage;
+pub use purge_schedule::PurgeSchedule;
 pub use task::Task;
 pub use task_dependency::TaskDependency;
+pub use task_github_link::TaskGithubLink;
+pub use task_lineage::TaskLineage;
+pub use task_template::TaskTemplate;
+pub use task_template_dependency::TaskTemplateDependency;
+pub use task_template_item::TaskTemplateItem;
+pub use veto_override::VetoOverride;
 pub use vote::Vote;
+pub use vote_delegation::VoteDelegation;
@@ -0,0 +1,11 @@
+use spacetimedb::{ScheduleAt, table};
+
+use crate::reducers::maintenance::purge::purge_deleted_rows;
+
+#[table(accessor = purge_schedule, scheduled(purge_deleted_rows))]
+pub struct PurgeSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
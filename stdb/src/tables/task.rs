@@ -32,4 +32,6 @@ pub struct Task {
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
     pub created_by: String,
+    #[default(None::<Vec<String>>)]
+    pub labels: Option<Vec<String>>,
 }
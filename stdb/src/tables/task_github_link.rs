@@ -0,0 +1,22 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::GithubRefKind;
+
+#[table(
+    accessor = task_github_links,
+    public,
+    index(accessor = by_task_id, btree(columns = [task_id])),
+    index(accessor = by_number, btree(columns = [number]))
+)]
+pub struct TaskGithubLink {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub task_id: u64,
+    pub project_id: u64,
+    pub kind: GithubRefKind,
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+    pub created_at: Timestamp,
+}
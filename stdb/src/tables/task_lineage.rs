@@ -0,0 +1,18 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(
+    accessor = task_lineage,
+    public,
+    index(accessor = by_task_id, btree(columns = [task_id])),
+    index(accessor = by_ancestor_id, btree(columns = [ancestor_id]))
+)]
+pub struct TaskLineage {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub task_id: u64,
+    pub ancestor_id: u64,
+    pub depth: u32,
+    pub discovery_id: u64,
+    pub created_at: Timestamp,
+}
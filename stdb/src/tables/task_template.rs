@@ -0,0 +1,14 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(accessor = task_templates, public)]
+pub struct TaskTemplate {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[unique]
+    pub name: String,
+    pub description: String,
+    pub created_by: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
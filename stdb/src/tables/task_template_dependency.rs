@@ -0,0 +1,20 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::types::DependencyType;
+
+#[table(
+    accessor = task_template_dependencies,
+    public,
+    index(accessor = by_template_id, btree(columns = [template_id])),
+    index(accessor = by_item_id, btree(columns = [item_id]))
+)]
+pub struct TaskTemplateDependency {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub template_id: u64,
+    pub item_id: u64,
+    pub depends_on_item_id: u64,
+    pub dependency_type: DependencyType,
+    pub created_at: Timestamp,
+}
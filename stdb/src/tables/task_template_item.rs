@@ -0,0 +1,18 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(
+    accessor = task_template_items,
+    public,
+    index(accessor = by_template_id, btree(columns = [template_id]))
+)]
+pub struct TaskTemplateItem {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub template_id: u64,
+    pub title: String,
+    pub description: String,
+    pub priority: u8,
+    pub labels: Vec<String>,
+    pub created_at: Timestamp,
+}
@@ -0,0 +1,15 @@
+use spacetimedb::{Timestamp, table};
+
+use crate::tables::vote::Vote;
+
+#[table(accessor = veto_overrides, public, index(accessor = by_idea_id, btree(columns = [idea_id])))]
+pub struct VetoOverride {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub idea_id: u64,
+    pub overridden_by: String,
+    pub reason: String,
+    pub vetoes: Vec<Vote>,
+    pub created_at: Timestamp,
+}
@@ -11,4 +11,10 @@ pub struct Vote {
     pub agent_id: String,
     pub vote_type: VoteType,
     pub created_at: Timestamp,
+    #[default(None::<String>)]
+    pub reason: Option<String>,
+    #[default(1)]
+    pub weight: u16,
+    #[default(false)]
+    pub flagged: bool,
 }
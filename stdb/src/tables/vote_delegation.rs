@@ -0,0 +1,12 @@
+use spacetimedb::{Timestamp, table};
+
+#[table(accessor = vote_delegations, public, index(accessor = by_delegator_id, btree(columns = [delegator_id])))]
+pub struct VoteDelegation {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub delegator_id: String,
+    pub delegate_id: String,
+    pub category: Option<String>,
+    pub created_at: Timestamp,
+}
@@ -73,24 +73,39 @@ pub enum IdeaStatus {
     ApprovedForProject,
     Rejected,
     Implemented,
+    Expired,
+    Withdrawn,
+    Merged,
+    Draft,
+    Discussion,
 }
 
 impl IdeaStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
+            IdeaStatus::Draft => "draft",
+            IdeaStatus::Discussion => "discussion",
             IdeaStatus::Voting => "voting",
             IdeaStatus::ApprovedForProject => "approved_for_project",
             IdeaStatus::Rejected => "rejected",
             IdeaStatus::Implemented => "implemented",
+            IdeaStatus::Expired => "expired",
+            IdeaStatus::Withdrawn => "withdrawn",
+            IdeaStatus::Merged => "merged",
         }
     }
 
     pub fn parse(s: &str) -> Option<Self> {
         match s {
+            "draft" => Some(IdeaStatus::Draft),
+            "discussion" => Some(IdeaStatus::Discussion),
             "voting" => Some(IdeaStatus::Voting),
             "approved_for_project" => Some(IdeaStatus::ApprovedForProject),
             "rejected" => Some(IdeaStatus::Rejected),
             "implemented" => Some(IdeaStatus::Implemented),
+            "expired" => Some(IdeaStatus::Expired),
+            "withdrawn" => Some(IdeaStatus::Withdrawn),
+            "merged" => Some(IdeaStatus::Merged),
             _ => None,
         }
     }
@@ -101,6 +116,8 @@ pub enum ProjectStatus {
     #[default]
     Active,
     Paused,
+    Completed,
+    Archived,
 }
 
 impl ProjectStatus {
@@ -108,6 +125,8 @@ impl ProjectStatus {
         match self {
             ProjectStatus::Active => "active",
             ProjectStatus::Paused => "paused",
+            ProjectStatus::Completed => "completed",
+            ProjectStatus::Archived => "archived",
         }
     }
 
@@ -115,6 +134,8 @@ impl ProjectStatus {
         match s {
             "active" => Some(ProjectStatus::Active),
             "paused" => Some(ProjectStatus::Paused),
+            "completed" => Some(ProjectStatus::Completed),
+            "archived" => Some(ProjectStatus::Archived),
             _ => None,
         }
     }
@@ -203,6 +224,7 @@ impl MessageType {
 pub enum DependencyType {
     Blocks,
     ParentChild,
+    DiscoveredFrom,
 }
 
 impl DependencyType {
@@ -210,6 +232,7 @@ impl DependencyType {
         match self {
             DependencyType::Blocks => "blocks",
             DependencyType::ParentChild => "parent-child",
+            DependencyType::DiscoveredFrom => "discovered-from",
         }
     }
 
@@ -217,6 +240,7 @@ impl DependencyType {
         match s {
             "blocks" => Some(DependencyType::Blocks),
             "parent-child" => Some(DependencyType::ParentChild),
+            "discovered-from" => Some(DependencyType::DiscoveredFrom),
             _ => None,
         }
     }
@@ -228,6 +252,7 @@ pub enum DiscoveredTaskStatus {
     Approved,
     Rejected,
     EscalatedToIdea,
+    Duplicate,
 }
 
 impl DiscoveredTaskStatus {
@@ -237,6 +262,7 @@ impl DiscoveredTaskStatus {
             DiscoveredTaskStatus::Approved => "approved",
             DiscoveredTaskStatus::Rejected => "rejected",
             DiscoveredTaskStatus::EscalatedToIdea => "escalated_to_idea",
+            DiscoveredTaskStatus::Duplicate => "duplicate",
         }
     }
 }
@@ -246,4 +272,294 @@ pub enum DiscoveryDecision {
     ApproveAsTask,
     Reject,
     EscalateToIdea,
+    MarkDuplicate,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum GithubRefKind {
+    Issue,
+    PullRequest,
+}
+
+impl GithubRefKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GithubRefKind::Issue => "issue",
+            GithubRefKind::PullRequest => "pull_request",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "issue" => Some(GithubRefKind::Issue),
+            "pull_request" => Some(GithubRefKind::PullRequest),
+            _ => None,
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum GithubEventKind {
+    PullRequestOpened,
+    PullRequestMerged,
+    PullRequestClosed,
+    IssueClosed,
+}
+
+impl GithubEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GithubEventKind::PullRequestOpened => "pull_request_opened",
+            GithubEventKind::PullRequestMerged => "pull_request_merged",
+            GithubEventKind::PullRequestClosed => "pull_request_closed",
+            GithubEventKind::IssueClosed => "issue_closed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pull_request_opened" => Some(GithubEventKind::PullRequestOpened),
+            "pull_request_merged" => Some(GithubEventKind::PullRequestMerged),
+            "pull_request_closed" => Some(GithubEventKind::PullRequestClosed),
+            "issue_closed" => Some(GithubEventKind::IssueClosed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum GithubEventOutcome {
+    Applied,
+    Unmatched,
+    Failed,
+}
+
+impl GithubEventOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GithubEventOutcome::Applied => "applied",
+            GithubEventOutcome::Unmatched => "unmatched",
+            GithubEventOutcome::Failed => "failed",
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq, Default)]
+pub enum IdeaKind {
+    #[default]
+    Project,
+    Governance,
+}
+
+impl IdeaKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdeaKind::Project => "project",
+            IdeaKind::Governance => "governance",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "project" => Some(IdeaKind::Project),
+            "governance" => Some(IdeaKind::Governance),
+            _ => None,
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub struct ConfigChange {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub struct AppliedConfigChange {
+    pub key: String,
+    pub previous_value: Option<String>,
+    pub value: String,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum GovernanceActionKind {
+    Applied,
+    Vetoed,
+    Failed,
+}
+
+impl GovernanceActionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GovernanceActionKind::Applied => "applied",
+            GovernanceActionKind::Vetoed => "vetoed",
+            GovernanceActionKind::Failed => "failed",
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum FundingRoundStatus {
+    Open,
+    Closed,
+}
+
+impl FundingRoundStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FundingRoundStatus::Open => "open",
+            FundingRoundStatus::Closed => "closed",
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub struct PointAllocation {
+    pub idea_id: u64,
+    pub points: u32,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub struct FundingResult {
+    pub idea_id: u64,
+    pub rank: u32,
+    pub score: u64,
+    pub backers: u32,
+    pub approved: bool,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum DuplicateTargetKind {
+    Discovery,
+    Task,
+}
+
+impl DuplicateTargetKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DuplicateTargetKind::Discovery => "discovery",
+            DuplicateTargetKind::Task => "task",
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateTarget {
+    pub kind: DuplicateTargetKind,
+    pub id: u64,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 4] = [
+        Severity::Low,
+        Severity::Medium,
+        Severity::High,
+        Severity::Critical,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "low" => Some(Severity::Low),
+            "medium" => Some(Severity::Medium),
+            "high" => Some(Severity::High),
+            "critical" => Some(Severity::Critical),
+            _ => None,
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum TaskType {
+    Bug,
+    Feature,
+    Improvement,
+    Refactor,
+    Docs,
+    Test,
+    Chore,
+    Security,
+}
+
+impl TaskType {
+    pub const ALL: [TaskType; 8] = [
+        TaskType::Bug,
+        TaskType::Feature,
+        TaskType::Improvement,
+        TaskType::Refactor,
+        TaskType::Docs,
+        TaskType::Test,
+        TaskType::Chore,
+        TaskType::Security,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskType::Bug => "bug",
+            TaskType::Feature => "feature",
+            TaskType::Improvement => "improvement",
+            TaskType::Refactor => "refactor",
+            TaskType::Docs => "docs",
+            TaskType::Test => "test",
+            TaskType::Chore => "chore",
+            TaskType::Security => "security",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "bug" => Some(TaskType::Bug),
+            "feature" => Some(TaskType::Feature),
+            "improvement" => Some(TaskType::Improvement),
+            "refactor" => Some(TaskType::Refactor),
+            "docs" => Some(TaskType::Docs),
+            "test" => Some(TaskType::Test),
+            "chore" => Some(TaskType::Chore),
+            "security" => Some(TaskType::Security),
+            _ => None,
+        }
+    }
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum TriageAction {
+    ApproveAsTask,
+    Reject,
+    NotifyZoe,
+}
+
+impl TriageAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TriageAction::ApproveAsTask => "approve_as_task",
+            TriageAction::Reject => "reject",
+            TriageAction::NotifyZoe => "notify_zoe",
+        }
+    }
+}
+
+/// Conditions a discovery must meet for a triage rule to fire. Empty lists
+/// match anything; all given conditions must hold.
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub struct TriageConditions {
+    pub task_types: Vec<TaskType>,
+    pub severities: Vec<Severity>,
+    pub min_reputation: Option<u32>,
+    pub discoverer_ids: Vec<String>,
 }
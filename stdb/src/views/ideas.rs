@@ -0,0 +1,21 @@
+use spacetimedb::{ViewContext, view};
+
+use crate::tables::agent::agents__view;
+use crate::tables::draft_idea::draft_ideas__view;
+use crate::tables::idea::Idea;
+
+/// The caller's own drafts, which stay out of the public `ideas` table until
+/// discussion opens.
+#[view(accessor = my_draft_ideas, public)]
+fn my_draft_ideas(ctx: &ViewContext) -> Vec<Idea> {
+    let Some(agent) = ctx.db.agents().identity().find(ctx.sender()) else {
+        return Vec::new();
+    };
+
+    ctx.db
+        .draft_ideas()
+        .by_created_by()
+        .filter(&agent.id)
+        .map(|draft| draft.idea)
+        .collect()
+}
@@ -0,0 +1,2 @@
+pub mod ideas;
+pub mod notifications;
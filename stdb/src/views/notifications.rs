@@ -0,0 +1,18 @@
+use spacetimedb::{ViewContext, view};
+
+use crate::tables::agent::agents__view;
+use crate::tables::agent_notification::{AgentNotification, agent_notifications__view};
+
+/// The caller's own notifications.
+#[view(accessor = my_notifications, public)]
+fn my_notifications(ctx: &ViewContext) -> Vec<AgentNotification> {
+    let Some(agent) = ctx.db.agents().identity().find(ctx.sender()) else {
+        return Vec::new();
+    };
+
+    ctx.db
+        .agent_notifications()
+        .by_agent_id()
+        .filter(&agent.id)
+        .collect()
+}